@@ -1,4 +1,4 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{black_box, BatchSize, criterion_group, criterion_main, Criterion};
 use ben_collections::collections::stacklist::StackList;
 
 pub fn bench_push_pop(c: &mut Criterion) {
@@ -6,11 +6,35 @@ pub fn bench_push_pop(c: &mut Criterion) {
     c.bench_function("push_pop", |b| b.iter(|| { list.push(black_box(123)).expect("!"); list.pop() }));
 }
 
+pub fn bench_push_unchecked_pop(c: &mut Criterion) {
+    let mut list: StackList<i32, 100> = StackList::new();
+    c.bench_function("push_unchecked_pop", |b| b.iter(|| { unsafe { list.push_unchecked(black_box(123)) }; list.pop() }));
+}
+
 pub fn bench_into_iter(c: &mut Criterion) {
     let mut list: StackList<i32, 100> = StackList::new();
     for i in 0..99 { list.push(i).expect("!"); }
     c.bench_function("IntoIter", |b| b.iter(|| { list.iter().map(black_box) }));
 }
 
-criterion_group!(benches, bench_push_pop, bench_into_iter);
+pub fn bench_clone(c: &mut Criterion) {
+    let list: StackList<u64, 100> = (0..100).collect();
+    c.bench_function("clone", |b| b.iter(|| black_box(&list).clone()));
+}
+
+pub fn bench_copy_from(c: &mut Criterion) {
+    let list: StackList<u64, 100> = (0..100).collect();
+    let mut copy: StackList<u64, 100> = StackList::new();
+    c.bench_function("copy_from", |b| b.iter(|| { copy.copy_from(black_box(&list)); copy.len() }));
+}
+
+pub fn bench_clear(c: &mut Criterion) {
+    c.bench_function("clear", |b| b.iter_batched_ref(|| StackList::<u8, 4096>::filled(1), |list| list.clear(), BatchSize::SmallInput));
+}
+
+pub fn bench_clear_fast(c: &mut Criterion) {
+    c.bench_function("clear_fast", |b| b.iter_batched_ref(|| StackList::<u8, 4096>::filled(1), |list| list.clear_fast(), BatchSize::SmallInput));
+}
+
+criterion_group!(benches, bench_push_pop, bench_push_unchecked_pop, bench_into_iter, bench_clone, bench_copy_from, bench_clear, bench_clear_fast);
 criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,15 @@
+//! Checks that the crate can be used from a `#![no_std]` crate.
+//! Build with `cargo build --example no_std --no-default-features`.
+#![no_std]
+
+use ben_collections::collections::stacklist::StackList;
+
+pub fn sum_moves() -> i32 {
+    let mut list: StackList<i32, 8> = StackList::new();
+
+    list.push(1).expect("Couldn't push to list.");
+    list.push(2).expect("Couldn't push to list.");
+    list.push(3).expect("Couldn't push to list.");
+
+    list.iter().sum()
+}
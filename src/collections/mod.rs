@@ -1 +1,6 @@
-pub mod stacklist;
\ No newline at end of file
+pub mod stacklist;
+pub mod stackstring;
+pub mod stackdeque;
+pub mod stackqueue;
+pub mod stackmap;
+pub mod stackset;
\ No newline at end of file
@@ -0,0 +1,290 @@
+//! This module describes a double-ended queue which is stored entirely on the
+//! stack as a ring buffer. Items can be pushed and popped at either end in
+//! constant time. A user must specify maximum capacity and contained type at
+//! compile time. An error is returned if attempting to push past the maximum
+//! capacity.
+
+use core::{mem, fmt::Debug, iter::FusedIterator};
+
+use super::stacklist::ListError;
+
+/// A double-ended queue stored on the stack of type T up to a maximum number of items S
+pub struct StackDeque<T: Sized, const S: usize> {
+    data: [mem::MaybeUninit<T>; S],
+    head: usize,
+    len: usize
+}
+
+impl<T: Sized, const S: usize> StackDeque<T, S> {
+    /// Map an offset from the front of the queue to its slot in the ring.
+    fn slot(&self, offset: usize) -> usize {
+        let index = self.head + offset;
+        if index >= S { index - S } else { index }
+    }
+
+    /// Push an item to the back of the queue.
+    /// If the queue is full, the item is handed back inside the error.
+    pub fn push_back(&mut self, item: T) -> Result<(), ListError<T>> {
+        if self.is_full() {
+            return Err(ListError::ListFull(item))
+        }
+
+        let slot = self.slot(self.len);
+        self.data[slot].write(item);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Push an item to the front of the queue.
+    /// If the queue is full, the item is handed back inside the error.
+    pub fn push_front(&mut self, item: T) -> Result<(), ListError<T>> {
+        if self.is_full() {
+            return Err(ListError::ListFull(item))
+        }
+
+        self.head = if self.head == 0 { S - 1 } else { self.head - 1 };
+        self.data[self.head].write(item);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Remove and return the item at the front of the queue. None if empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None
+        }
+
+        let item = unsafe { self.data[self.head].assume_init_read() };
+        self.head = self.slot(1);
+        self.len -= 1;
+
+        Some(item)
+    }
+
+    /// Remove and return the item at the back of the queue. None if empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None
+        }
+
+        self.len -= 1;
+        let slot = self.slot(self.len);
+
+        Some(unsafe { self.data[slot].assume_init_read() })
+    }
+
+    /// Return a reference to the item at the front of the queue. None if empty.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Return a reference to the item at the back of the queue. None if empty.
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.checked_sub(1)?)
+    }
+
+    /// Return a reference to the item at the given offset from the front. None if out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            Some(unsafe { self.data[self.slot(index)].assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of items currently in the queue.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the maximum number of items the queue can hold.
+    pub const fn capacity(&self) -> usize {
+        S
+    }
+
+    /// Returns true if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the queue is full.
+    pub fn is_full(&self) -> bool {
+        self.len == S
+    }
+
+    /// Drop every item in the queue, leaving it empty.
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// Get an iterator over the items in the queue, from front to back.
+    pub fn iter(&self) -> StackDequeIter<'_, T, S> {
+        StackDequeIter {
+            deque: self,
+            front: 0,
+            back: self.len
+        }
+    }
+
+    /// Initialize an empty queue on the stack
+    pub const fn new() -> Self {
+        StackDeque {
+            data: [const { mem::MaybeUninit::uninit() }; S],
+            head: 0,
+            len: 0
+        }
+    }
+}
+
+impl<T: Sized, const S: usize> Drop for StackDeque<T, S> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Sized, const S: usize> Default for StackDeque<T, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug, const S: usize> Debug for StackDeque<T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the items in the queue (Iterator)
+pub struct StackDequeIter<'a, T: Sized, const S: usize> {
+    deque: &'a StackDeque<T, S>,
+    front: usize,
+    back: usize
+}
+
+impl<'a, T: Sized, const S: usize> Iterator for StackDequeIter<'a, T, S> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            // End of queue reached.
+            None
+        } else {
+            self.front += 1;
+            self.deque.get(self.front - 1)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: Sized, const S: usize> DoubleEndedIterator for StackDequeIter<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+            self.deque.get(self.back)
+        }
+    }
+}
+
+impl<'a, T: Sized, const S: usize> ExactSizeIterator for StackDequeIter<'a, T, S> {}
+
+impl<'a, T: Sized, const S: usize> FusedIterator for StackDequeIter<'a, T, S> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    /// Increments a shared counter each time it is dropped.
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut deque: StackDeque<i32, 3> = StackDeque::new();
+
+        deque.push_back(2).expect("Couldn't push to deque.");
+        deque.push_front(1).expect("Couldn't push to deque.");
+        deque.push_back(3).expect("Couldn't push to deque.");
+        assert!(deque.is_full());
+        assert!(matches!(deque.push_back(4), Err(ListError::ListFull(4))));
+        assert!(matches!(deque.push_front(0), Err(ListError::ListFull(0))));
+
+        assert!(deque.front() == Some(&1));
+        assert!(deque.back() == Some(&3));
+        assert!(deque.iter().copied().eq([1, 2, 3]));
+
+        assert!(deque.pop_front() == Some(1));
+        assert!(deque.pop_back() == Some(3));
+        assert!(deque.pop_back() == Some(2));
+        assert!(deque.pop_front().is_none());
+        assert!(deque.pop_back().is_none());
+        assert!(deque.front().is_none());
+        assert!(deque.back().is_none());
+    }
+
+    #[test]
+    fn test_wraparound() {
+        let mut deque: StackDeque<i32, 4> = StackDeque::new();
+
+        // Walk the ring round several times in both directions.
+        for i in 0..20 {
+            deque.push_back(i).expect("Couldn't push to deque.");
+            deque.push_back(i + 100).expect("Couldn't push to deque.");
+            assert!(deque.pop_front() == Some(i));
+            assert!(deque.pop_front() == Some(i + 100));
+        }
+        for i in 0..20 {
+            deque.push_front(i).expect("Couldn't push to deque.");
+            assert!(deque.pop_back() == Some(i));
+        }
+
+        deque.push_back(2).expect("Couldn't push to deque.");
+        deque.push_back(3).expect("Couldn't push to deque.");
+        deque.push_front(1).expect("Couldn't push to deque.");
+        deque.push_front(0).expect("Couldn't push to deque.");
+        assert!(deque.iter().copied().eq([0, 1, 2, 3]));
+        assert!(deque.iter().rev().copied().eq([3, 2, 1, 0]));
+        assert!(deque.len() == 4);
+    }
+
+    #[test]
+    fn test_drop() {
+        let drops = Cell::new(0);
+
+        {
+            let mut deque: StackDeque<DropCounter, 3> = StackDeque::new();
+            for _ in 0..3 {
+                deque.push_front(DropCounter(&drops)).expect("Couldn't push to deque.");
+            }
+
+            drop(deque.pop_back());
+            deque.push_front(DropCounter(&drops)).expect("Couldn't push to deque."); // Wraps around
+            assert!(drops.get() == 1);
+        }
+
+        assert!(drops.get() == 4);
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let mut deque: StackDeque<i32, 0> = StackDeque::new();
+
+        assert!(deque.push_back(1).is_err());
+        assert!(deque.push_front(1).is_err());
+        assert!(deque.pop_front().is_none());
+        assert!(deque.iter().next().is_none());
+    }
+}
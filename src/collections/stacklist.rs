@@ -1,26 +1,40 @@
-use std::{mem, fmt::{Debug, Display}};
+//! This module describes a list which can be pushed to and iterated through
+//! which is stored entirely on the stack. A user must specify maximum capacity
+//! and contained type at compile time. An error is returned if attempting to push 
+//! past the maximum capacity.
+//! 
+//! Used in-engine to store moves.
 
-/// This module describes a list which can be pushed to and iterated through
-/// which is stored entirely on the stack. A user must specify maximum capacity
-/// and contained type at compile time. An error is returned if attempting to push 
-/// past the maximum capacity.
-/// 
-/// Used in-engine to store moves.
+use core::{mem, ptr, slice, borrow::{Borrow, BorrowMut}, cmp::Ordering, fmt::{Debug, Display}, hash::{Hash, Hasher}, iter::{self, FusedIterator}, ops::{Bound, Deref, DerefMut, Index, IndexMut, RangeBounds}};
 
-#[derive(Debug)]
-pub enum ListError {
-    /// Raised when attempting to push an item to the list which would overflow
-    ListFull,
+/// An error returned by fallible list operations.
+#[derive(Clone, PartialEq, Eq)]
+pub enum ListError<T = ()> {
+    /// Raised when attempting to push an item to the list which would overflow.
+    /// Carries the rejected item back to the caller, or `()` where there is no single item to return.
+    ListFull(T),
 }
 
-impl Display for ListError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+// Implemented by hand so that errors are printable whether or not T is.
+impl<T> Debug for ListError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            Self::ListFull => write!(f, "The list is full.")
+            Self::ListFull(_) => write!(f, "ListFull(..)")
         }
     }
 }
 
+impl<T> Display for ListError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ListFull(_) => write!(f, "The list is full.")
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for ListError<T> {}
+
 /// A list stored on the stack of type T up to a maximum number of items S
 pub struct StackList<T: Sized, const S: usize> {
     data: [mem::MaybeUninit<T>; S],
@@ -28,10 +42,14 @@ pub struct StackList<T: Sized, const S: usize> {
 }
 
 impl<T: Sized, const S: usize> StackList<T, S> {
-    /// Push an item to the end of the list
-    pub fn push(&mut self, item: T) -> Result<(), ListError> {
+    /// The maximum number of items the list can hold.
+    pub const CAPACITY: usize = S;
+
+    /// Push an item to the end of the list.
+    /// If the list is full, the item is handed back inside the error.
+    pub fn push(&mut self, item: T) -> Result<(), ListError<T>> {
         if self.is_full() {
-            return Err(ListError::ListFull)
+            return Err(ListError::ListFull(item))
         }
 
         self.data[self.writer_index].write(item);
@@ -40,6 +58,244 @@ impl<T: Sized, const S: usize> StackList<T, S> {
         Ok(())
     }
 
+    /// Push an item to the end of the list.
+    /// If the list is full, the item itself is handed back as the error.
+    pub fn try_push(&mut self, item: T) -> Result<(), T> {
+        self.push(item).map_err(|ListError::ListFull(item)| item)
+    }
+
+    /// Push an item to the end of the list without checking whether there is room for it.
+    ///
+    /// # Safety
+    /// The caller must guarantee that the list is not full.
+    pub unsafe fn push_unchecked(&mut self, item: T) {
+        debug_assert!(!self.is_full());
+
+        unsafe { self.data.get_unchecked_mut(self.writer_index).write(item) };
+        self.writer_index += 1;
+    }
+
+    /// Push an item to the end of the list, evicting the item at the front if the list is full.
+    /// Returns the evicted item, or None if there was room.
+    /// Eviction shifts the remaining items along, so this is O(n) once the list is full.
+    pub fn push_overwrite(&mut self, item: T) -> Option<T> {
+        if S == 0 {
+            // Nothing can be stored, so the new item is evicted straight away.
+            return Some(item)
+        }
+
+        let evicted = if self.is_full() { Some(self.remove(0)) } else { None };
+        // Either the list had room, or evicting made some.
+        unsafe { self.push_unchecked(item) };
+
+        evicted
+    }
+
+    /// Push an item onto the top of the stack, evicting the item at the bottom if the stack is full.
+    /// Returns the evicted item, or None if there was room.
+    /// This is the same operation as `push_overwrite`, named for bounded-history use where the list
+    /// is treated as a stack: `pop` keeps returning the most recent items, and only the oldest is lost.
+    /// `push_overwrite` describes it in queue terms, as pushing to the back and evicting the front.
+    pub fn cycle_push(&mut self, item: T) -> Option<T> {
+        self.push_overwrite(item)
+    }
+
+    /// Insert an item at the given index, shifting every item after it along by one.
+    /// If the list is full, the item is handed back inside the error.
+    ///
+    /// # Panics
+    /// Panics if `index > len()`.
+    pub fn insert(&mut self, index: usize, item: T) -> Result<(), ListError<T>> {
+        let len = self.writer_index;
+        if index > len {
+            panic!("insertion index (is {}) should be <= len (is {})", index, len)
+        }
+        if self.is_full() {
+            return Err(ListError::ListFull(item))
+        }
+
+        unsafe {
+            let slot = self.data.as_mut_ptr().add(index);
+            // Moving the MaybeUninits bitwise means nothing is dropped or duplicated.
+            ptr::copy(slot, slot.add(1), len - index);
+            (*slot).write(item);
+        }
+        self.writer_index += 1;
+
+        Ok(())
+    }
+
+    /// Insert an item into a sorted list at the position which keeps it sorted, returning that index.
+    /// An item equal to existing items is inserted after them, so insertion order is kept among equals.
+    /// If the list is full, the item is handed back inside the error.
+    pub fn insert_sorted(&mut self, item: T) -> Result<usize, ListError<T>> where T: Ord {
+        let index = self.as_slice().partition_point(|existing| *existing <= item);
+        self.insert(index, item)?;
+
+        Ok(index)
+    }
+
+    /// Remove and return the item at the given index, shifting every item after it back by one.
+    ///
+    /// # Panics
+    /// Panics if `index >= len()`.
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.writer_index;
+        if index >= len {
+            panic!("removal index (is {}) should be < len (is {})", index, len)
+        }
+
+        self.writer_index -= 1;
+        unsafe {
+            let slot = self.data.as_mut_ptr().add(index);
+            let item = (*slot).assume_init_read();
+            // The vacated last slot is left behind as logically uninitialised.
+            ptr::copy(slot.add(1), slot, len - index - 1);
+
+            item
+        }
+    }
+
+    /// Remove and return the item at the given index, replacing it with the last item.
+    /// This doesn't preserve order, but avoids shifting the rest of the list.
+    ///
+    /// # Panics
+    /// Panics if `index >= len()`.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.writer_index;
+        if index >= len {
+            panic!("swap_remove index (is {}) should be < len (is {})", index, len)
+        }
+
+        self.swap(index, len - 1);
+        self.pop().expect("List was empty.")
+    }
+
+    /// Swap the items at indices `i` and `j`.
+    ///
+    /// # Panics
+    /// Panics if either index is `>= len()`.
+    pub fn swap(&mut self, i: usize, j: usize) {
+        self.as_mut_slice().swap(i, j);
+    }
+
+    /// Reverse the order of the items in place.
+    pub fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+
+    /// Rotate the items in place so the item at `mid` becomes the first.
+    ///
+    /// # Panics
+    /// Panics if `mid > len()`.
+    pub fn rotate_left(&mut self, mid: usize) {
+        self.as_mut_slice().rotate_left(mid);
+    }
+
+    /// Rotate the items in place so the last `k` items move to the front.
+    ///
+    /// # Panics
+    /// Panics if `k > len()`.
+    pub fn rotate_right(&mut self, k: usize) {
+        self.as_mut_slice().rotate_right(k);
+    }
+
+    /// Sort the items in ascending order. The sort is stable.
+    /// Stable sorting needs an allocator, so this requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn sort(&mut self) where T: Ord {
+        self.as_mut_slice().sort();
+    }
+
+    /// Stably sort the items with a comparator function.
+    #[cfg(feature = "std")]
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        self.as_mut_slice().sort_by(compare);
+    }
+
+    /// Stably sort the items by a key extracted from each.
+    #[cfg(feature = "std")]
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, f: F) {
+        self.as_mut_slice().sort_by_key(f);
+    }
+
+    /// Sort the items in ascending order, without preserving the order of equal items.
+    /// Works in place without allocating.
+    pub fn sort_unstable(&mut self) where T: Ord {
+        self.as_mut_slice().sort_unstable();
+    }
+
+    /// Binary search a sorted list for an item.
+    /// Returns `Ok` with its index if found, otherwise `Err` with the index it could be inserted at to keep the list sorted.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize> where T: Ord {
+        self.as_slice().binary_search(x)
+    }
+
+    /// Binary search a sorted list with a comparator function, which should return the ordering of an item relative to the target.
+    pub fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize> {
+        self.as_slice().binary_search_by(f)
+    }
+
+    /// Clone every item of a slice onto the end of the list.
+    /// If the slice won't fit, an error is returned and the list is left untouched.
+    pub fn extend_from_slice(&mut self, other: &[T]) -> Result<(), ListError> where T: Clone {
+        if !self.has_room_for(other.len()) {
+            return Err(ListError::ListFull(()))
+        }
+
+        self.extend(other.iter().cloned());
+
+        Ok(())
+    }
+
+    /// Push clones of `value` until the list is full, keeping the existing items.
+    /// Note this differs from the slice method of the same name, which overwrites existing items.
+    pub fn fill(&mut self, value: T) where T: Clone {
+        self.fill_with(|| value.clone());
+    }
+
+    /// Push items returned by `f` until the list is full, keeping the existing items.
+    pub fn fill_with<F: FnMut() -> T>(&mut self, mut f: F) {
+        while !self.is_full() {
+            self.data[self.writer_index].write(f());
+            self.writer_index += 1;
+        }
+    }
+
+    /// Resize the list to `new_len`, either dropping items from the end or pushing clones of `value`.
+    /// If `new_len` is past the capacity, the value is handed back inside the error and the list is left untouched.
+    pub fn resize(&mut self, new_len: usize, value: T) -> Result<(), ListError<T>> where T: Clone {
+        if new_len > S {
+            return Err(ListError::ListFull(value))
+        }
+
+        if new_len <= self.writer_index {
+            self.truncate(new_len);
+        } else {
+            let added = new_len - self.writer_index;
+            self.extend(iter::repeat_n(value, added));
+        }
+
+        Ok(())
+    }
+
+    /// Resize the list to `new_len`, either dropping items from the end or pushing items returned by `f`.
+    /// If `new_len` is past the capacity, an error is returned and the list is left untouched.
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, f: F) -> Result<(), ListError> {
+        if new_len > S {
+            return Err(ListError::ListFull(()))
+        }
+
+        if new_len <= self.writer_index {
+            self.truncate(new_len);
+        } else {
+            let added = new_len - self.writer_index;
+            self.extend(iter::repeat_with(f).take(added));
+        }
+
+        Ok(())
+    }
+
     /// Return the item at the top of the stack. None if empty.
     pub fn pop(&mut self) -> Option<T> {
         if self.is_empty() {
@@ -55,157 +311,3315 @@ impl<T: Sized, const S: usize> StackList<T, S> {
         }
     }
 
-    /// Returns true if the list is full.
-    pub fn is_full(&self) -> bool {
-        self.writer_index == S
+    /// Pop and return the item at the top of the stack if `f` returns true for it.
+    /// Otherwise the list is left unchanged and None is returned.
+    pub fn pop_if<F: FnOnce(&T) -> bool>(&mut self, f: F) -> Option<T> {
+        if f(self.peek()?) {
+            self.pop()
+        } else {
+            None
+        }
     }
 
-    /// Returns true if the list is empty.
-    pub fn is_empty(&self) -> bool {
-        self.writer_index == 0
+    /// Return a reference to the item at the top of the stack. None if empty.
+    pub fn peek(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(unsafe { self.data[self.writer_index - 1].assume_init_ref() })
+        }
     }
 
-    /// Get an iterator over the items in the stack.
-    /// Iterates FIFO.
-    pub fn iter(&self) -> StackListIter<'_, T, S> {
-        StackListIter {
-            list: self,
-            reader_index: 0
+    /// Return a reference to the item `offset` places below the top of the stack, where 0 is the top.
+    /// None if the offset reaches past the bottom.
+    pub fn peek_at(&self, offset: usize) -> Option<&T> {
+        self.get(self.writer_index.checked_sub(offset)?.checked_sub(1)?)
+    }
+
+    /// Return a mutable reference to the item at the top of the stack. None if empty.
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(unsafe { self.data[self.writer_index - 1].assume_init_mut() })
         }
     }
 
-    /// Initialize an empty list on the stack
-    pub fn new() -> Self {
-        StackList {
-            data: unsafe { mem::MaybeUninit::uninit().assume_init() }, // actually an initialised list of MaybeUninit<T>s
-            writer_index: 0
+    /// Return a reference to the item at the given index, counting FIFO.
+    /// None if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.writer_index {
+            Some(unsafe { self.data[index].assume_init_ref() })
+        } else {
+            None
         }
     }
-}
 
-/// An iterator over the items in the list (Iterator)
-pub struct StackListIter<'a, T: Sized, const S: usize> {
-    list: &'a StackList<T, S>,
-    reader_index: usize
-}
+    /// Return a mutable reference to the item at the given index, counting FIFO.
+    /// None if the index is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.writer_index {
+            Some(unsafe { self.data[index].assume_init_mut() })
+        } else {
+            None
+        }
+    }
 
-impl<'a, T: Sized, const S: usize> Iterator for StackListIter<'a, T, S> {
-    type Item = &'a T;
+    /// Return mutable references to the items at two different indices at once.
+    /// None if the indices are equal or either is out of bounds.
+    pub fn get_disjoint_mut(&mut self, i: usize, j: usize) -> Option<(&mut T, &mut T)> {
+        let len = self.writer_index;
+        if i == j || i >= len || j >= len {
+            return None
+        }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.reader_index == self.list.writer_index {
-            // End of list reached.
-            None
+        // Splitting between the indices gives two non-overlapping borrows.
+        let items = self.as_mut_slice();
+        if i < j {
+            let (front, back) = items.split_at_mut(j);
+            Some((&mut front[i], &mut back[0]))
         } else {
-            let ret = &self.list.data[self.reader_index];
+            let (front, back) = items.split_at_mut(i);
+            Some((&mut back[0], &mut front[j]))
+        }
+    }
 
-            self.reader_index += 1;
+    /// Return a reference to the first item in the list. None if empty.
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
 
-            Some(unsafe { ret.assume_init_ref() })
-        }
+    /// Return a mutable reference to the first item in the list. None if empty.
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = (self.list.writer_index - self.reader_index) + 1;
-        (remaining, Some(remaining))
+    /// Return a reference to the last item in the list. None if empty.
+    /// This is the same item as `peek`.
+    pub fn last(&self) -> Option<&T> {
+        self.peek()
     }
-}
 
+    /// Return a mutable reference to the last item in the list. None if empty.
+    /// This is the same item as `peek_mut`.
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.peek_mut()
+    }
 
-// IntoIterator
-pub struct IntoIter<T: Sized, const S: usize>(StackList<T, S>);
+    /// Return the first item and a slice of the rest. None if empty.
+    pub fn split_first(&self) -> Option<(&T, &[T])> {
+        self.as_slice().split_first()
+    }
 
-impl<T: Sized, const S: usize> Iterator for IntoIter<T, S> {
-    type Item = T;
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.pop()
+    /// Return the last item and a slice of the rest. None if empty.
+    pub fn split_last(&self) -> Option<(&T, &[T])> {
+        self.as_slice().split_last()
     }
-}
 
-impl<T: Sized, const S: usize> IntoIterator for StackList<T, S> {
-    type Item = T;
-    type IntoIter = IntoIter<T, S>;
+    /// Divide the items into two slices at `mid`, the first holding the items before it.
+    ///
+    /// # Panics
+    /// Panics if `mid > len()`.
+    pub fn split_at(&self, mid: usize) -> (&[T], &[T]) {
+        self.as_slice().split_at(mid)
+    }
 
-    /// Consume the list and produce an iterator.
-    /// Iterates LIFO
-    fn into_iter(self) -> Self::IntoIter {
-        IntoIter(self)
+    /// Divide the items into two mutable slices at `mid`, the first holding the items before it.
+    ///
+    /// # Panics
+    /// Panics if `mid > len()`.
+    pub fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+        self.as_mut_slice().split_at_mut(mid)
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    /// Get a slice over the items in the list, in FIFO order.
+    pub fn as_slice(&self) -> &[T] {
+        // The first writer_index slots are always initialised, and MaybeUninit<T> has the same layout as T.
+        unsafe { slice::from_raw_parts(self.data.as_ptr() as *const T, self.writer_index) }
+    }
 
-    #[test]
-    fn test_push_pop() {
-        let mut list: StackList<i32, 3> = StackList::new();
+    /// Get a mutable slice over the items in the list, in FIFO order.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut T, self.writer_index) }
+    }
 
-        assert!(list.push(1).is_ok());
-        assert!(list.push(2).is_ok());
-        assert!(list.push(3).is_ok());
-        assert!(matches!(list.push(4).unwrap_err(), ListError::ListFull)); // Uh oh! Maximum capacity reached!
+    /// View the raw bytes of the items in the list, `len() * size_of::<T>()` of them in FIFO order.
+    /// The bytes are in native endianness, and the slice has alignment 1 whatever the alignment of T.
+    ///
+    /// # Safety
+    /// T must be plain old data: every byte of its representation must be initialized.
+    /// Types with padding, such as `(u8, u32)` or most structs and enums, leave some bytes
+    /// uninitialized and so must not be viewed this way. Primitive integers and floats,
+    /// and arrays of them, are fine.
+    pub unsafe fn as_bytes(&self) -> &[u8] where T: Copy {
+        unsafe { slice::from_raw_parts(self.as_ptr() as *const u8, mem::size_of_val(self.as_slice())) }
+    }
 
-        assert!(list.pop() == Some(3));
-        assert!(list.pop() == Some(2));
-        assert!(list.pop() == Some(1));
-        assert!(list.pop().is_none()); // The list is now empty
-        assert!(list.pop().is_none());
+    /// Keep only the items for which `f` returns true, dropping the rest.
+    /// The kept items stay in their original order.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|item| f(item));
     }
 
-    #[test]
-    fn test_iter() {
-        let mut list: StackList<i32, 3> = StackList::new();
+    /// Keep only the items for which `f` returns true, as `retain` does, returning how many were dropped.
+    pub fn retain_count<F: FnMut(&T) -> bool>(&mut self, f: F) -> usize {
+        let old_len = self.writer_index;
+        self.retain(f);
 
-        list.push(1).expect("Couldn't push to list.");
-        list.push(2).expect("Couldn't push to list.");
-        list.push(3).expect("Couldn't push to list.");
+        old_len - self.writer_index
+    }
 
-        let mut expected = 0;
+    /// Keep only the items for which `f` returns true, dropping the rest.
+    /// `f` may mutate the items, and mutations to kept items persist.
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        let len = self.writer_index;
+        // Treat the list as empty while compacting, so a panic in `f` leaks items rather than dropping any twice.
+        self.writer_index = 0;
 
-        for i in list.iter() {
-            expected += 1;
-            assert!(*i == expected);
+        let base = self.data.as_mut_ptr();
+        let mut kept = 0;
+        for index in 0..len {
+            unsafe {
+                let slot = base.add(index);
+                if f((*slot).assume_init_mut()) {
+                    ptr::copy(slot, base.add(kept), 1);
+                    kept += 1;
+                } else {
+                    (*slot).assume_init_drop();
+                }
+            }
         }
+
+        self.writer_index = kept;
     }
 
-    #[test]
-    fn test_into_iter() {
-        let mut list: StackList<i32, 3> = StackList::new();
+    /// Sort the items in ascending order and remove any duplicates, leaving each distinct item once.
+    /// Uses `sort_unstable`, so it works without allocating.
+    pub fn sort_dedup(&mut self) where T: Ord {
+        self.sort_unstable();
+        self.dedup();
+    }
 
-        list.push(1).expect("Couldn't push to list.");
-        list.push(2).expect("Couldn't push to list.");
-        list.push(3).expect("Couldn't push to list.");
+    /// Remove consecutive repeated items, keeping the first of each run.
+    pub fn dedup(&mut self) where T: PartialEq {
+        self.dedup_by(|a, b| a == b);
+    }
 
-        let mut expected = 4;
+    /// Remove consecutive items which map to the same key, keeping the first of each run.
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
 
-        for i in list.into_iter() {
-            expected -= 1;
-            assert!(i == expected);
+    /// Remove each item for which `same(item, previous_kept_item)` returns true.
+    fn dedup_by<F: FnMut(&T, &T) -> bool>(&mut self, mut same: F) {
+        let len = self.writer_index;
+        if len <= 1 {
+            return
+        }
+        // As with retain, a panic in `same` leaks items rather than dropping any twice.
+        self.writer_index = 0;
+
+        let mut kept = 1;
+        for index in 1..len {
+            unsafe {
+                let base = self.data.as_mut_ptr();
+                let slot = base.add(index);
+                if same((*slot).assume_init_ref(), (*base.add(kept - 1)).assume_init_ref()) {
+                    (*slot).assume_init_drop();
+                } else {
+                    ptr::copy(slot, base.add(kept), 1);
+                    kept += 1;
+                }
+            }
         }
+
+        self.writer_index = kept;
     }
 
-    #[test]
-    fn test_is_empty() {
-        let mut list: StackList<i32, 3> = StackList::new();
+    /// Returns true if the list contains an item equal to the one given.
+    pub fn contains(&self, item: &T) -> bool where T: PartialEq {
+        self.as_slice().contains(item)
+    }
 
-        assert!(list.is_empty());
-        list.push(1).expect("Couldn't push to list.");
-        assert!(!list.is_empty());
-        list.pop();
-        assert!(list.is_empty());
+    /// Return the index of the first item equal to the one given. None if there is none.
+    pub fn index_of(&self, item: &T) -> Option<usize> where T: PartialEq {
+        self.iter().position(|i| i == item)
     }
 
-    #[test]
-    fn test_is_full() {
-        let mut list: StackList<i32, 2> = StackList::new();
+    /// Return the index of the last item equal to the one given. None if there is none.
+    pub fn last_index_of(&self, item: &T) -> Option<usize> where T: PartialEq {
+        self.iter().rposition(|i| i == item)
+    }
 
-        assert!(!list.is_full());
-        list.push(1).expect("Couldn't push to list.");
-        assert!(!list.is_full());
-        list.push(1).expect("Couldn't push to list.");
-        assert!(list.is_full());
-        list.pop();
-        assert!(!list.is_full());
+    /// Return the index of the first item, in FIFO order, for which `f` returns true. None if there is none.
+    pub fn position<F: FnMut(&T) -> bool>(&self, f: F) -> Option<usize> {
+        self.iter().position(f)
+    }
+
+    /// Returns the number of items for which `f` returns true.
+    pub fn count<F: FnMut(&T) -> bool>(&self, mut f: F) -> usize {
+        self.iter().filter(|item| f(item)).count()
+    }
+
+    /// Remove the items in the given range, yielding them from the returned iterator.
+    /// When the iterator is dropped, any items it didn't yield are dropped and the gap is closed.
+    /// If the iterator is leaked instead, the items from the start of the range onwards are leaked too.
+    ///
+    /// # Panics
+    /// Panics if the range starts after it ends, or ends past `len()`.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, S> {
+        let len = self.writer_index;
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.checked_add(1).expect("Range start overflowed."),
+            Bound::Unbounded => 0
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.checked_add(1).expect("Range end overflowed."),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len
+        };
+        if start > end {
+            panic!("drain range starts at {} but ends at {}", start, end)
+        }
+        if end > len {
+            panic!("drain range end (is {}) should be <= len (is {})", end, len)
+        }
+
+        // Only the items before the range are live until the Drain closes the gap.
+        self.writer_index = start;
+
+        Drain {
+            list: self,
+            front: start,
+            back: end,
+            tail_start: end,
+            tail_len: len - end
+        }
+    }
+
+    /// Remove up to the first `n` items, yielding them in FIFO order from the returned iterator.
+    /// Asking for more items than the list holds drains the whole list.
+    /// Dropping the iterator early behaves as for `drain`.
+    pub fn drain_front(&mut self, n: usize) -> Drain<'_, T, S> {
+        let end = n.min(self.writer_index);
+        self.drain(..end)
+    }
+
+    /// Remove the items for which `f` returns true, yielding them in FIFO order from the returned iterator.
+    /// The kept items stay in their original order. If the iterator is dropped early, the remaining
+    /// items are still checked and those matching `f` are dropped. If `f` panics, the items it hadn't
+    /// yet decided on are kept.
+    /// If the iterator is leaked instead, every item in the list is leaked too.
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, f: F) -> ExtractIf<'_, T, S, F> {
+        let old_len = self.writer_index;
+        // Treat the list as empty while extracting, so a leaked iterator can't expose moved-out items.
+        self.writer_index = 0;
+
+        ExtractIf {
+            list: self,
+            index: 0,
+            kept: 0,
+            old_len,
+            pred: f,
+            panicked: false
+        }
+    }
+
+    /// Split the list in two at the given index, moving the items from `at` onwards into a new list.
+    ///
+    /// # Panics
+    /// Panics if `at > len()`.
+    pub fn split_off(&mut self, at: usize) -> StackList<T, S> {
+        let len = self.writer_index;
+        if at > len {
+            panic!("split_off index (is {}) should be <= len (is {})", at, len)
+        }
+
+        let mut other = Self::new();
+        unsafe {
+            ptr::copy_nonoverlapping(self.data.as_ptr().add(at), other.data.as_mut_ptr(), len - at);
+        }
+        self.writer_index = at;
+        other.writer_index = len - at;
+
+        other
     }
-}
\ No newline at end of file
+
+    /// Clone the items into a new `Vec`, in FIFO order.
+    #[cfg(feature = "std")]
+    pub fn to_vec(&self) -> Vec<T> where T: Clone {
+        self.as_slice().to_vec()
+    }
+
+    /// Consume the list, moving its items into a new `Vec` in FIFO order.
+    #[cfg(feature = "std")]
+    pub fn into_vec(self) -> Vec<T> {
+        // The items now belong to the Vec, so the list mustn't drop them too.
+        let list = mem::ManuallyDrop::new(self);
+        let mut vec = Vec::with_capacity(list.len());
+
+        unsafe {
+            ptr::copy_nonoverlapping(list.as_ptr(), vec.as_mut_ptr(), list.len());
+            vec.set_len(list.len());
+        }
+
+        vec
+    }
+
+    /// Consume two lists, moving the items of `self` then `other` into a new list of capacity `OUT`.
+    ///
+    /// Stable Rust can't compute `S + A2` in a type, so the caller names the output capacity,
+    /// usually through a type annotation: `let joined: StackList<_, 7> = a.concat(b);` for capacities 3 and 4.
+    /// `OUT` must be at least `S + A2`, which is checked at compile time.
+    pub fn concat<const A2: usize, const OUT: usize>(self, other: StackList<T, A2>) -> StackList<T, OUT> {
+        const { assert!(OUT >= S + A2, "concat output capacity must be at least the sum of the input capacities") };
+
+        // The items are moved into the output, so neither input may drop them.
+        let first = mem::ManuallyDrop::new(self);
+        let second = mem::ManuallyDrop::new(other);
+        let mut out = StackList::new();
+
+        unsafe {
+            let base = out.data.as_mut_ptr() as *mut T;
+            ptr::copy_nonoverlapping(first.as_ptr(), base, first.len());
+            ptr::copy_nonoverlapping(second.as_ptr(), base.add(first.len()), second.len());
+        }
+        out.writer_index = first.len() + second.len();
+
+        out
+    }
+
+    /// Move the items into a list of a different capacity, such as a tighter one once the final size is known.
+    /// If they won't all fit, the list is handed back unchanged inside the error.
+    pub fn try_into_capacity<const S2: usize>(self) -> Result<StackList<T, S2>, Self> {
+        if self.len() > S2 {
+            return Err(self)
+        }
+
+        // The items are moved into the output, so the input mustn't drop them.
+        let list = mem::ManuallyDrop::new(self);
+        let mut out = StackList::new();
+
+        unsafe {
+            ptr::copy_nonoverlapping(list.as_ptr(), out.data.as_mut_ptr() as *mut T, list.len());
+        }
+        out.writer_index = list.len();
+
+        Ok(out)
+    }
+
+    /// Move every item of `other` onto the end of this list, leaving `other` empty.
+    /// If they won't all fit, an error is returned and neither list is changed.
+    pub fn append<const S2: usize>(&mut self, other: &mut StackList<T, S2>) -> Result<(), ListError> {
+        let count = other.len();
+        if !self.has_room_for(count) {
+            return Err(ListError::ListFull(()))
+        }
+
+        unsafe {
+            let dest = (self.data.as_mut_ptr() as *mut T).add(self.writer_index);
+            ptr::copy_nonoverlapping(other.as_ptr(), dest, count);
+        }
+        // The items now belong to this list.
+        other.writer_index = 0;
+        self.writer_index += count;
+
+        Ok(())
+    }
+
+    /// Overwrite this list with a copy of another, in a single bulk copy.
+    /// This is cheaper than `clone` for Copy types, which would copy the items one at a time.
+    pub fn copy_from(&mut self, other: &Self) where T: Copy {
+        // Copy types can't implement Drop, so the old items can be overwritten without dropping them.
+        unsafe {
+            ptr::copy_nonoverlapping(other.data.as_ptr(), self.data.as_mut_ptr(), other.writer_index);
+        }
+        self.writer_index = other.writer_index;
+    }
+
+    /// Overwrite every item in the list with those of a slice of the same length, in a single bulk copy.
+    /// Unlike `copy_from`, the length of the list is left unchanged.
+    ///
+    /// # Panics
+    /// Panics if `src.len() != len()`, as the slice method does.
+    pub fn copy_from_slice(&mut self, src: &[T]) where T: Copy {
+        if src.len() != self.writer_index {
+            panic!("source slice length ({}) does not match destination slice length ({})", src.len(), self.writer_index)
+        }
+
+        // The list's own borrow rules out `src` overlapping it.
+        unsafe {
+            ptr::copy_nonoverlapping(src.as_ptr(), self.data.as_mut_ptr() as *mut T, src.len());
+        }
+    }
+
+    /// Drop every item in the list, leaving it empty.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Empty the list in constant time without visiting any items.
+    /// Only available for Copy types, which have no drop glue to run.
+    pub fn clear_fast(&mut self) where T: Copy {
+        self.writer_index = 0;
+    }
+
+    /// Drop every item past the first `len`, rolling the list back to that length.
+    /// Does nothing if the list is already no longer than `len`.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.writer_index {
+            return
+        }
+
+        let old_len = self.writer_index;
+        self.writer_index = len;
+
+        for slot in &mut self.data[len..old_len] {
+            unsafe { slot.assume_init_drop() }
+        }
+    }
+
+    /// Returns true if the list is full.
+    pub const fn is_full(&self) -> bool {
+        self.len() == S
+    }
+
+    /// Returns true if the list is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of items currently in the list.
+    pub const fn len(&self) -> usize {
+        self.writer_index
+    }
+
+    /// Returns the maximum number of items the list can hold.
+    pub const fn capacity(&self) -> usize {
+        S
+    }
+
+    /// Returns the number of items which can still be pushed before the list is full.
+    pub const fn remaining_capacity(&self) -> usize {
+        S - self.writer_index
+    }
+
+    /// Returns true if `n` more items can be pushed without the list overflowing.
+    pub const fn has_room_for(&self, n: usize) -> bool {
+        n <= self.remaining_capacity()
+    }
+
+    /// Get an iterator over the items in the stack.
+    /// Iterates FIFO, the same order as the slice the list derefs to.
+    pub fn iter(&self) -> StackListIter<'_, T, S> {
+        StackListIter {
+            list: self,
+            reader_index: 0,
+            back_index: self.writer_index
+        }
+    }
+
+    /// Get an iterator over the items in the stack paired with their indices, in FIFO order.
+    pub fn indexed(&self) -> impl ExactSizeIterator<Item = (usize, &T)> + DoubleEndedIterator {
+        self.iter().enumerate()
+    }
+
+    /// Get an iterator over the items in the stack from the top down, the order they would be popped in.
+    pub fn iter_rev(&self) -> impl ExactSizeIterator<Item = &T> {
+        self.iter().rev()
+    }
+
+    /// Get an iterator over every overlapping window of `size` items, in FIFO order.
+    /// Yields nothing if `size > len()`.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    pub fn windows(&self, size: usize) -> slice::Windows<'_, T> {
+        self.as_slice().windows(size)
+    }
+
+    /// Get an iterator over non-overlapping chunks of `size` items, in FIFO order.
+    /// The last chunk is shorter if `len()` isn't a multiple of `size`.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    pub fn chunks(&self, size: usize) -> slice::Chunks<'_, T> {
+        self.as_slice().chunks(size)
+    }
+
+    /// Get an iterator over non-overlapping chunks of exactly `size` items, in FIFO order.
+    /// Any remainder which doesn't fill a whole chunk is skipped, and can be read from the iterator's `remainder`.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    pub fn chunks_exact(&self, size: usize) -> slice::ChunksExact<'_, T> {
+        self.as_slice().chunks_exact(size)
+    }
+
+    /// Get an iterator yielding mutable references to the items in the stack.
+    /// Iterates FIFO.
+    pub fn iter_mut(&mut self) -> StackListIterMut<'_, T, S> {
+        StackListIterMut {
+            remaining: self.as_mut_slice()
+        }
+    }
+
+    /// Consume the list and produce an iterator which iterates FIFO.
+    /// This is the opposite order to `into_iter`, which pops items LIFO.
+    pub fn into_iter_front(self) -> IntoIterFront<T, S> {
+        IntoIterFront {
+            list: self,
+            reader_index: 0
+        }
+    }
+
+    /// Consume the list, applying `f` to each item in FIFO order to build a list of the results.
+    /// If `f` panics, both the remaining items and the results so far are dropped.
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> StackList<U, S> {
+        self.into_iter_front().map(f).collect()
+    }
+
+    /// Consume the list, applying `f` to each item in FIFO order and keeping the `Some` results.
+    pub fn filter_map<U, F: FnMut(T) -> Option<U>>(self, f: F) -> StackList<U, S> {
+        StackList::from_filter_map(self.into_iter_front(), f)
+    }
+
+    /// Build a list from the `Some` results of applying `f` to each item of an iterator.
+    /// Like `collect`, this stops pulling from the iterator once the list is full, so later items are never passed to `f`.
+    pub fn from_filter_map<U, I: IntoIterator<Item = U>, F: FnMut(U) -> Option<T>>(iter: I, f: F) -> Self {
+        iter.into_iter().filter_map(f).collect()
+    }
+
+    /// Consume two lists, pairing up their items in FIFO order.
+    /// The result is as long as the shorter list, and any unpaired items from the longer one are dropped.
+    pub fn zip<U, const S2: usize>(self, other: StackList<U, S2>) -> StackList<(T, U), S> {
+        self.into_iter_front().zip(other.into_iter_front()).collect()
+    }
+
+    /// Initialize an empty list on the stack
+    pub const fn new() -> Self {
+        StackList {
+            data: [const { mem::MaybeUninit::uninit() }; S],
+            writer_index: 0
+        }
+    }
+
+    /// Build a full list of clones of `value`.
+    /// If a clone panics, the clones made so far are dropped.
+    pub fn filled(value: T) -> Self where T: Clone {
+        let mut list = Self::new();
+        list.fill(value);
+
+        list
+    }
+
+    /// Build a full list by calling `f` with each index from 0 to S.
+    /// If `f` panics, the items created so far are dropped.
+    pub fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {
+        (0..S).map(f).collect()
+    }
+
+    /// Build a list from an iterator, returning an error if it yields more items than will fit.
+    /// Unlike `collect`, which stops at capacity, this pulls one item past capacity to detect overflow.
+    /// The error carries the first item which didn't fit.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, ListError<T>> {
+        let mut list = Self::new();
+        list.extend_checked(iter)?;
+
+        Ok(list)
+    }
+
+    /// Push every item from an iterator, returning an error if it yields more items than will fit.
+    /// Items pushed before the overflow remain in the list, and the error carries the first item which didn't fit.
+    pub fn extend_checked<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), ListError<T>> {
+        for item in iter {
+            self.push(item)?;
+        }
+
+        Ok(())
+    }
+
+    /// Push items from an iterator until it runs out or the list is full, returning how many were pushed.
+    /// No item is pulled from the iterator once the list is full, so passing `iter.by_ref()`
+    /// leaves the overflow in place for the caller rather than dropping it.
+    pub fn push_all<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        let old_len = self.writer_index;
+        self.extend(iter);
+
+        self.writer_index - old_len
+    }
+}
+
+impl<T: Sized, const INNER: usize, const S: usize> StackList<StackList<T, INNER>, S> {
+    /// Consume a list of lists, moving every inner item into a single list in order.
+    /// The output capacity `OUT` is usually given through a type annotation, like `concat`.
+    /// `OUT` must be at least `S * INNER`, so that the items fit even if every list is full,
+    /// which is checked at compile time.
+    pub fn flatten<const OUT: usize>(self) -> StackList<T, OUT> {
+        const { assert!(OUT >= S * INNER, "flatten output capacity must be at least the outer capacity times the inner capacity") };
+
+        self.into_iter_front().flat_map(StackList::into_iter_front).collect()
+    }
+}
+
+impl<T: Sized, const S: usize> Drop for StackList<T, S> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Sized, const S: usize> Default for StackList<T, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug, const S: usize> Debug for StackList<T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Display, const S: usize> Display for StackList<T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[")?;
+        for (i, item) in self.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<T: Clone, const S: usize> Clone for StackList<T, S> {
+    fn clone(&self) -> Self {
+        let mut list = Self::new();
+
+        // writer_index only advances once a slot is written, so if a clone panics
+        // the partial list drops exactly the items cloned so far.
+        for item in self.iter() {
+            list.data[list.writer_index].write(item.clone());
+            list.writer_index += 1;
+        }
+
+        list
+    }
+}
+
+/// Lists compare equal if their items are, regardless of capacity.
+impl<T: PartialEq, const S1: usize, const S2: usize> PartialEq<StackList<T, S2>> for StackList<T, S1> {
+    fn eq(&self, other: &StackList<T, S2>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const S: usize> Eq for StackList<T, S> {}
+
+impl<T: PartialEq, const S: usize, const N: usize> PartialEq<[T; N]> for StackList<T, S> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, const S: usize> PartialEq<&[T]> for StackList<T, S> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: PartialEq, const S: usize> PartialEq<Vec<T>> for StackList<T, S> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+/// Lists are ordered lexicographically, like slices.
+impl<T: PartialOrd, const S: usize> PartialOrd for StackList<T, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord, const S: usize> Ord for StackList<T, S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Hashes the length followed by each item, exactly as the equivalent slice would.
+impl<T: Hash, const S: usize> Hash for StackList<T, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+/// Builds a full list from an array of exactly the list's capacity.
+impl<T: Sized, const S: usize> From<[T; S]> for StackList<T, S> {
+    fn from(items: [T; S]) -> Self {
+        let mut list = Self::new();
+
+        // Each item is moved out of the array exactly once.
+        for (slot, item) in list.data.iter_mut().zip(items) {
+            slot.write(item);
+        }
+        list.writer_index = S;
+
+        list
+    }
+}
+
+/// Clones the items of a slice into a new list. Fails if the slice is longer than the capacity.
+impl<T: Clone, const S: usize> TryFrom<&[T]> for StackList<T, S> {
+    type Error = ListError;
+
+    fn try_from(items: &[T]) -> Result<Self, Self::Error> {
+        if items.len() > S {
+            return Err(ListError::ListFull(()))
+        }
+
+        Ok(items.iter().cloned().collect())
+    }
+}
+
+/// Collects items until the list is full, then stops pulling from the iterator.
+/// Any items past the capacity are never taken, and are dropped along with the iterator.
+/// Use `StackList::try_from_iter` to treat overflow as an error instead.
+impl<T: Sized, const S: usize> FromIterator<T> for StackList<T, S> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+
+        list
+    }
+}
+
+/// Pushes items until the list is full, then stops pulling from the iterator.
+/// Use `StackList::extend_checked` to treat overflow as an error instead.
+impl<T: Sized, const S: usize> Extend<T> for StackList<T, S> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+
+        while !self.is_full() {
+            match iter.next() {
+                Some(item) => {
+                    self.data[self.writer_index].write(item);
+                    self.writer_index += 1;
+                }
+                None => break
+            }
+        }
+    }
+}
+
+/// Serializes the items as a plain sequence.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const S: usize> serde::Serialize for StackList<T, S> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Deserializes a sequence of items, failing if it holds more than will fit.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const S: usize> serde::Deserialize<'de> for StackList<T, S> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, SeqAccess, Visitor};
+
+        struct StackListVisitor<T, const S: usize>(core::marker::PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>, const S: usize> Visitor<'de> for StackListVisitor<T, S> {
+            type Value = StackList<T, S>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "a sequence of at most {} items", S)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                // Bail out early if the format already knows the sequence is too long.
+                if let Some(len) = seq.size_hint() {
+                    if len > S {
+                        return Err(A::Error::invalid_length(len, &self))
+                    }
+                }
+
+                let mut list = StackList::new();
+                while let Some(item) = seq.next_element()? {
+                    if list.push(item).is_err() {
+                        return Err(A::Error::invalid_length(S + 1, &self))
+                    }
+                }
+
+                Ok(list)
+            }
+        }
+
+        deserializer.deserialize_seq(StackListVisitor(core::marker::PhantomData))
+    }
+}
+
+/// Generates a list of between 0 and S arbitrary items.
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>, const S: usize> arbitrary::Arbitrary<'a> for StackList<T, S> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(0..=S)?;
+
+        let mut list = Self::new();
+        for _ in 0..len {
+            let item = T::arbitrary(u)?;
+            // len is at most S, so there's always room.
+            unsafe { list.push_unchecked(item) };
+        }
+
+        Ok(list)
+    }
+}
+
+impl<T: Sized, const S: usize> Index<usize> for StackList<T, S> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len();
+        self.get(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {} but the index is {}", len, index)
+        })
+    }
+}
+
+impl<T: Sized, const S: usize> IndexMut<usize> for StackList<T, S> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len();
+        self.get_mut(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {} but the index is {}", len, index)
+        })
+    }
+}
+
+/// Derefs to the initialised items as a slice, in FIFO order.
+impl<T: Sized, const S: usize> Deref for StackList<T, S> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T: Sized, const S: usize> DerefMut for StackList<T, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Sized, const S: usize> AsRef<[T]> for StackList<T, S> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Sized, const S: usize> AsMut<[T]> for StackList<T, S> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Sized, const S: usize> Borrow<[T]> for StackList<T, S> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Sized, const S: usize> BorrowMut<[T]> for StackList<T, S> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+/// An iterator over the items in the list (Iterator)
+pub struct StackListIter<'a, T: Sized, const S: usize> {
+    list: &'a StackList<T, S>,
+    reader_index: usize,
+    back_index: usize
+}
+
+impl<'a, T: Sized, const S: usize> Iterator for StackListIter<'a, T, S> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reader_index == self.back_index {
+            // End of list reached.
+            None
+        } else {
+            let ret = &self.list.data[self.reader_index];
+
+            self.reader_index += 1;
+
+            Some(unsafe { ret.assume_init_ref() })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back_index - self.reader_index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: Sized, const S: usize> DoubleEndedIterator for StackListIter<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.reader_index == self.back_index {
+            // Met the front of the iterator.
+            None
+        } else {
+            self.back_index -= 1;
+
+            Some(unsafe { self.list.data[self.back_index].assume_init_ref() })
+        }
+    }
+}
+
+impl<'a, T: Sized, const S: usize> ExactSizeIterator for StackListIter<'a, T, S> {}
+
+impl<'a, T: Sized, const S: usize> FusedIterator for StackListIter<'a, T, S> {}
+
+// Implemented by hand so that the iterator is cloneable whether or not T is.
+impl<'a, T: Sized, const S: usize> Clone for StackListIter<'a, T, S> {
+    fn clone(&self) -> Self {
+        StackListIter {
+            list: self.list,
+            reader_index: self.reader_index,
+            back_index: self.back_index
+        }
+    }
+}
+
+/// An iterator over mutable references to the items in the list (Iterator)
+pub struct StackListIterMut<'a, T: Sized, const S: usize> {
+    remaining: &'a mut [T]
+}
+
+impl<'a, T: Sized, const S: usize> Iterator for StackListIterMut<'a, T, S> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Split the head off so every reference handed out is disjoint from what's left.
+        let (first, rest) = mem::take(&mut self.remaining).split_first_mut()?;
+        self.remaining = rest;
+
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining.len(), Some(self.remaining.len()))
+    }
+}
+
+impl<'a, T: Sized, const S: usize> DoubleEndedIterator for StackListIterMut<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (last, rest) = mem::take(&mut self.remaining).split_last_mut()?;
+        self.remaining = rest;
+
+        Some(last)
+    }
+}
+
+impl<'a, T: Sized, const S: usize> ExactSizeIterator for StackListIterMut<'a, T, S> {}
+
+impl<'a, T: Sized, const S: usize> FusedIterator for StackListIterMut<'a, T, S> {}
+
+/// A draining iterator over a range of the list, created by `StackList::drain`.
+pub struct Drain<'a, T: Sized, const S: usize> {
+    list: &'a mut StackList<T, S>,
+    front: usize,
+    back: usize,
+    tail_start: usize,
+    tail_len: usize
+}
+
+impl<'a, T: Sized, const S: usize> Iterator for Drain<'a, T, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            None
+        } else {
+            let item = unsafe { self.list.data[self.front].assume_init_read() };
+            self.front += 1;
+
+            Some(item)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: Sized, const S: usize> DoubleEndedIterator for Drain<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+
+            Some(unsafe { self.list.data[self.back].assume_init_read() })
+        }
+    }
+}
+
+impl<'a, T: Sized, const S: usize> ExactSizeIterator for Drain<'a, T, S> {}
+
+impl<'a, T: Sized, const S: usize> FusedIterator for Drain<'a, T, S> {}
+
+impl<'a, T: Sized, const S: usize> Drop for Drain<'a, T, S> {
+    fn drop(&mut self) {
+        // Drop anything which wasn't yielded.
+        let (front, back) = (self.front, self.back);
+        self.front = back;
+        for slot in &mut self.list.data[front..back] {
+            unsafe { slot.assume_init_drop() }
+        }
+
+        // Move the tail back to close the gap.
+        let start = self.list.writer_index;
+        unsafe {
+            let base = self.list.data.as_mut_ptr();
+            ptr::copy(base.add(self.tail_start), base.add(start), self.tail_len);
+        }
+        self.list.writer_index = start + self.tail_len;
+    }
+}
+
+/// An iterator removing and yielding the items which match a predicate (Iterator)
+pub struct ExtractIf<'a, T: Sized, const S: usize, F: FnMut(&mut T) -> bool> {
+    list: &'a mut StackList<T, S>,
+    index: usize,
+    kept: usize,
+    old_len: usize,
+    pred: F,
+    panicked: bool
+}
+
+impl<'a, T: Sized, const S: usize, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'a, T, S, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let base = self.list.data.as_mut_ptr();
+
+        while self.index < self.old_len {
+            unsafe {
+                let slot = base.add(self.index);
+
+                // Cleared again only if `pred` returns, so the Drop knows not to call it after a panic.
+                self.panicked = true;
+                let extract = (self.pred)((*slot).assume_init_mut());
+                self.panicked = false;
+                self.index += 1;
+
+                if extract {
+                    return Some((*slot).assume_init_read())
+                }
+
+                ptr::copy(slot, base.add(self.kept), 1);
+                self.kept += 1;
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.old_len - self.index))
+    }
+}
+
+impl<'a, T: Sized, const S: usize, F: FnMut(&mut T) -> bool> FusedIterator for ExtractIf<'a, T, S, F> {}
+
+impl<'a, T: Sized, const S: usize, F: FnMut(&mut T) -> bool> Drop for ExtractIf<'a, T, S, F> {
+    fn drop(&mut self) {
+        // Finish the job, unless `pred` has already panicked.
+        if !self.panicked {
+            self.for_each(drop);
+        }
+
+        // Keep whatever `pred` didn't get to, moving it back to close the gap.
+        let unchecked = self.old_len - self.index;
+        unsafe {
+            let base = self.list.data.as_mut_ptr();
+            ptr::copy(base.add(self.index), base.add(self.kept), unchecked);
+        }
+        self.list.writer_index = self.kept + unchecked;
+    }
+}
+
+
+// IntoIterator
+/// A consuming iterator over the items in the list, LIFO (Iterator)
+/// Each item is popped as it's yielded, so the wrapped list's own Drop
+/// cleans up exactly the items which were never consumed.
+pub struct IntoIter<T: Sized, const S: usize>(StackList<T, S>);
+
+impl<T: Sized, const S: usize> Iterator for IntoIter<T, S> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop()
+    }
+}
+
+impl<T: Sized, const S: usize> FusedIterator for IntoIter<T, S> {}
+
+impl<T: Sized, const S: usize> IntoIterator for StackList<T, S> {
+    type Item = T;
+    type IntoIter = IntoIter<T, S>;
+
+    /// Consume the list and produce an iterator.
+    /// Iterates LIFO
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+/// A consuming iterator over the items in the list, FIFO (Iterator)
+pub struct IntoIterFront<T: Sized, const S: usize> {
+    list: StackList<T, S>,
+    reader_index: usize
+}
+
+impl<T: Sized, const S: usize> Iterator for IntoIterFront<T, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reader_index == self.list.writer_index {
+            None
+        } else {
+            let item = unsafe { self.list.data[self.reader_index].assume_init_read() };
+            self.reader_index += 1;
+
+            Some(item)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.list.writer_index - self.reader_index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Sized, const S: usize> ExactSizeIterator for IntoIterFront<T, S> {}
+
+impl<T: Sized, const S: usize> FusedIterator for IntoIterFront<T, S> {}
+
+impl<T: Sized, const S: usize> Drop for IntoIterFront<T, S> {
+    fn drop(&mut self) {
+        // Items before reader_index have already been moved out, so only drop the rest.
+        let (start, end) = (self.reader_index, self.list.writer_index);
+        self.list.writer_index = 0;
+
+        for slot in &mut self.list.data[start..end] {
+            unsafe { slot.assume_init_drop() }
+        }
+    }
+}
+
+impl<'a, T: Sized, const S: usize> IntoIterator for &'a StackList<T, S> {
+    type Item = &'a T;
+    type IntoIter = StackListIter<'a, T, S>;
+
+    /// Iterates FIFO
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Sized, const S: usize> IntoIterator for &'a mut StackList<T, S> {
+    type Item = &'a mut T;
+    type IntoIter = StackListIterMut<'a, T, S>;
+
+    /// Iterates FIFO
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    /// Increments a shared counter each time it is dropped.
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    /// Panics when the second clone is attempted.
+    struct PanicOnClone<'a> {
+        clones: &'a Cell<usize>,
+        drops: &'a Cell<usize>
+    }
+
+    impl Clone for PanicOnClone<'_> {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+            if self.clones.get() == 2 {
+                panic!("Clone failed.");
+            }
+            PanicOnClone { clones: self.clones, drops: self.drops }
+        }
+    }
+
+    impl Drop for PanicOnClone<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut list: StackList<i32, 3> = StackList::new();
+
+        assert!(list.push(1).is_ok());
+        assert!(list.push(2).is_ok());
+        assert!(list.push(3).is_ok());
+        assert!(matches!(list.push(4).unwrap_err(), ListError::ListFull(4))); // Uh oh! Maximum capacity reached!
+
+        assert!(list.pop() == Some(3));
+        assert!(list.pop() == Some(2));
+        assert!(list.pop() == Some(1));
+        assert!(list.pop().is_none()); // The list is now empty
+        assert!(list.pop().is_none());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut list: StackList<i32, 3> = StackList::new();
+
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        list.push(3).expect("Couldn't push to list.");
+
+        let mut expected = 0;
+
+        for i in list.iter() {
+            expected += 1;
+            assert!(*i == expected);
+        }
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut list: StackList<i32, 3> = StackList::new();
+
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        list.push(3).expect("Couldn't push to list.");
+
+        let mut expected = 4;
+
+        for i in list.into_iter() {
+            expected -= 1;
+            assert!(i == expected);
+        }
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let mut list: StackList<i32, 3> = StackList::new();
+
+        assert!(list.is_empty());
+        list.push(1).expect("Couldn't push to list.");
+        assert!(!list.is_empty());
+        list.pop();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_is_full() {
+        let mut list: StackList<i32, 2> = StackList::new();
+
+        assert!(!list.is_full());
+        list.push(1).expect("Couldn't push to list.");
+        assert!(!list.is_full());
+        list.push(1).expect("Couldn't push to list.");
+        assert!(list.is_full());
+        list.pop();
+        assert!(!list.is_full());
+    }
+
+    #[test]
+    fn test_drop() {
+        let drops = Cell::new(0);
+
+        {
+            let mut list: StackList<DropCounter, 4> = StackList::new();
+            list.push(DropCounter(&drops)).expect("Couldn't push to list.");
+            list.push(DropCounter(&drops)).expect("Couldn't push to list.");
+            list.push(DropCounter(&drops)).expect("Couldn't push to list.");
+
+            drop(list.pop());
+            assert!(drops.get() == 1); // Popped element was dropped by the caller
+        }
+
+        assert!(drops.get() == 3); // Only the two live elements were dropped with the list
+    }
+
+    #[test]
+    fn test_iter_size_hint() {
+        let mut list: StackList<i32, 3> = StackList::new();
+        assert!(list.iter().size_hint() == (0, Some(0)));
+
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        list.push(3).expect("Couldn't push to list.");
+
+        let mut iter = list.iter();
+        for remaining in (0..=3).rev() {
+            assert!(iter.size_hint() == (remaining, Some(remaining)));
+            iter.next();
+        }
+        assert!(iter.size_hint() == (0, Some(0))); // Exhausted
+    }
+
+    #[test]
+    fn test_iter_rev() {
+        let mut list: StackList<i32, 3> = StackList::new();
+
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        list.push(3).expect("Couldn't push to list.");
+
+        for (i, expected) in list.iter().rev().zip([3, 2, 1]) {
+            assert!(*i == expected);
+        }
+        assert!(list.iter().rev().count() == 3);
+    }
+
+    #[test]
+    fn test_iter_double_ended() {
+        let mut list: StackList<i32, 5> = StackList::new();
+
+        for i in 1..=5 {
+            list.push(i).expect("Couldn't push to list.");
+        }
+
+        let mut iter = list.iter();
+        assert!(iter.next() == Some(&1));
+        assert!(iter.next_back() == Some(&5));
+        assert!(iter.next() == Some(&2));
+        assert!(iter.next_back() == Some(&4));
+        assert!(iter.next() == Some(&3));
+        assert!(iter.next_back().is_none()); // Both ends have met
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_iter_len() {
+        let mut list: StackList<i32, 3> = StackList::new();
+
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        list.push(3).expect("Couldn't push to list.");
+
+        let mut iter = list.iter();
+        assert!(iter.len() == 3);
+
+        for remaining in (0..3).rev() {
+            iter.next();
+            assert!(iter.len() == remaining);
+        }
+    }
+
+    #[test]
+    fn test_fused() {
+        let mut list: StackList<i32, 3> = StackList::new();
+
+        list.push(1).expect("Couldn't push to list.");
+
+        let mut iter = list.iter();
+        assert!(iter.next() == Some(&1));
+        for _ in 0..3 {
+            assert!(iter.next().is_none());
+        }
+
+        let mut into_iter = list.into_iter();
+        assert!(into_iter.next() == Some(1));
+        for _ in 0..3 {
+            assert!(into_iter.next().is_none());
+        }
+    }
+
+    #[test]
+    fn test_len() {
+        let mut list: StackList<i32, 3> = StackList::new();
+
+        assert!(list.is_empty());
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        assert!(list.len() == 2);
+        list.pop();
+        assert!(list.len() == 1);
+        list.pop();
+        list.pop(); // Popping an empty list leaves the length at zero
+        assert!(list.is_empty());
+
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        list.clear();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_capacity() {
+        let list: StackList<i32, 3> = StackList::new();
+
+        assert!(list.capacity() == 3);
+
+        let sized_by_list = [0u8; StackList::<i32, 3>::CAPACITY];
+        assert!(sized_by_list.len() == list.capacity());
+    }
+
+    #[test]
+    fn test_clear() {
+        let drops = Cell::new(0);
+        let mut list: StackList<DropCounter, 4> = StackList::new();
+
+        list.push(DropCounter(&drops)).expect("Couldn't push to list.");
+        list.push(DropCounter(&drops)).expect("Couldn't push to list.");
+        list.push(DropCounter(&drops)).expect("Couldn't push to list.");
+
+        list.clear();
+        assert!(drops.get() == 3);
+        assert!(list.is_empty());
+
+        list.push(DropCounter(&drops)).expect("Couldn't push to list."); // The list can be reused
+        drop(list);
+        assert!(drops.get() == 4);
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut list: StackList<i32, 3> = StackList::new();
+
+        assert!(list.peek().is_none());
+        assert!(list.peek_mut().is_none());
+
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        assert!(list.peek() == Some(&2));
+
+        *list.peek_mut().expect("List was empty.") += 10;
+        assert!(list.peek() == Some(&12));
+        assert!(list.len() == 2); // Peeking doesn't remove anything
+
+        assert!(list.pop() == Some(12));
+        assert!(list.peek() == Some(&1));
+    }
+
+    #[test]
+    fn test_get() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        list.push(3).expect("Couldn't push to list.");
+
+        assert!(list.get(0) == Some(&1));
+        assert!(list.get(2) == Some(&3));
+        assert!(list.get(3).is_none()); // Past the written region, though within capacity
+        assert!(list.get(4).is_none());
+
+        *list.get_mut(1).expect("Index out of bounds.") = 20;
+        assert!(list.get(1) == Some(&20));
+        assert!(list.get_mut(3).is_none());
+    }
+
+    #[test]
+    fn test_index() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        list.push(3).expect("Couldn't push to list.");
+
+        assert!(list[0] == 1);
+        assert!(list[2] == 3);
+
+        list[1] = 20;
+        assert!(list[1] == 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 1 but the index is 1")]
+    fn test_index_out_of_bounds() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        list.push(1).expect("Couldn't push to list.");
+        let _ = list[1];
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 0 but the index is 0")]
+    fn test_index_mut_out_of_bounds() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        list[0] = 1;
+    }
+
+    #[test]
+    fn test_as_slice() {
+        let mut list: StackList<i32, 4> = StackList::new();
+        assert!(list.as_slice().is_empty());
+
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        list.push(3).expect("Couldn't push to list.");
+
+        assert!(list.as_slice().len() == list.len());
+        assert!(list.as_slice() == [1, 2, 3]);
+
+        list.as_mut_slice()[0] = 10;
+        assert!(list.as_mut_slice().len() == list.len());
+
+        let mut expected = [10, 2, 3].iter();
+        for i in list.iter() {
+            assert!(Some(i) == expected.next());
+        }
+    }
+
+    #[test]
+    fn test_deref() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        list.push(3).expect("Couldn't push to list.");
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+
+        assert!(list.contains(&1));
+        assert!(!list.contains(&4));
+        assert!(list.first() == Some(&3));
+        assert!((*list)[1..] == [1, 2]); // Ranges index through the slice
+
+        (*list).sort(); // Calls the slice method, not the inherent one
+        assert!(*list == [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        list.push(3).expect("Couldn't push to list.");
+
+        for i in list.iter_mut() {
+            *i *= 2;
+        }
+
+        assert!(*list == [2, 4, 6]);
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut list: StackList<String, 4> = StackList::new();
+
+        list.push(String::from("a")).expect("Couldn't push to list.");
+        list.push(String::from("b")).expect("Couldn't push to list.");
+
+        let mut cloned = list.clone();
+        assert!(*cloned == *list);
+
+        cloned[0].push('!');
+        cloned.push(String::from("c")).expect("Couldn't push to list.");
+
+        assert!(*list == ["a", "b"]);
+        assert!(*cloned == ["a!", "b", "c"]);
+    }
+
+    #[test]
+    fn test_clone_panic() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let clones = Cell::new(0);
+        let drops = Cell::new(0);
+        let mut list: StackList<PanicOnClone, 4> = StackList::new();
+
+        for _ in 0..3 {
+            list.push(PanicOnClone { clones: &clones, drops: &drops }).expect("Couldn't push to list.");
+        }
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| list.clone()));
+        assert!(result.is_err());
+        assert!(drops.get() == 1); // Only the one successful clone was dropped
+
+        drop(list);
+        assert!(drops.get() == 4);
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut list: StackList<i32, 4> = StackList::new();
+        assert!(format!("{:?}", list) == "[]");
+
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        list.push(3).expect("Couldn't push to list.");
+
+        assert!(format!("{:?}", list) == "[1, 2, 3]");
+    }
+
+    #[test]
+    fn test_display() {
+        let mut list: StackList<&str, 4> = StackList::new();
+        assert!(list.to_string() == "[]");
+
+        list.push("e2e4").expect("Couldn't push to list.");
+        assert!(list.to_string() == "[e2e4]");
+
+        list.push("d7d5").expect("Couldn't push to list.");
+        list.push("e4d5").expect("Couldn't push to list.");
+        assert!(list.to_string() == "[e2e4, d7d5, e4d5]");
+    }
+
+    #[test]
+    fn test_default() {
+        #[derive(Default)]
+        struct Position {
+            moves: StackList<i32, 4>
+        }
+
+        let position = Position::default();
+        assert!(position.moves.is_empty());
+    }
+
+    #[test]
+    fn test_eq() {
+        let mut a: StackList<i32, 4> = StackList::new();
+        let mut b: StackList<i32, 4> = StackList::new();
+        assert!(a == b);
+
+        a.push(1).expect("Couldn't push to list.");
+        a.push(2).expect("Couldn't push to list.");
+        b.push(1).expect("Couldn't push to list.");
+        assert!(a != b); // Different lengths
+
+        b.push(3).expect("Couldn't push to list.");
+        assert!(a != b); // Same length, different contents
+
+        b.pop();
+        b.push(2).expect("Couldn't push to list.");
+        assert!(a == b);
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut a: StackList<i32, 4> = StackList::new();
+        let mut b: StackList<i32, 4> = StackList::new();
+        assert!(a.cmp(&b) == Ordering::Equal);
+
+        a.push(1).expect("Couldn't push to list.");
+        a.push(2).expect("Couldn't push to list.");
+        b.push(1).expect("Couldn't push to list.");
+        assert!(b < a); // A prefix sorts before the longer list
+
+        b.push(3).expect("Couldn't push to list.");
+        assert!(a < b); // The first differing element decides
+
+        b.pop();
+        b.push(2).expect("Couldn't push to list.");
+        assert!(a.cmp(&b) == Ordering::Equal);
+        assert!(a.partial_cmp(&b) == Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::HashSet;
+
+        let mut a: StackList<i32, 4> = StackList::new();
+        let mut b: StackList<i32, 4> = StackList::new();
+        let mut c: StackList<i32, 4> = StackList::new();
+
+        a.push(1).expect("Couldn't push to list.");
+        a.push(2).expect("Couldn't push to list.");
+        b.push(1).expect("Couldn't push to list.");
+        b.push(2).expect("Couldn't push to list.");
+        c.push(2).expect("Couldn't push to list.");
+        c.push(1).expect("Couldn't push to list.");
+
+        let mut set = HashSet::new();
+        assert!(set.insert(a));
+        assert!(!set.insert(b)); // Equal lists collide
+        assert!(set.insert(c));
+        assert!(set.len() == 2);
+    }
+
+    #[test]
+    fn test_from_array() {
+        let list = StackList::from([1, 2, 3]);
+
+        assert!(list.is_full());
+        assert!(list.iter().copied().eq([1, 2, 3]));
+
+        let drops = Cell::new(0);
+        let list = StackList::from([DropCounter(&drops), DropCounter(&drops)]);
+        assert!(drops.get() == 0); // Nothing was dropped in the move
+        drop(list);
+        assert!(drops.get() == 2);
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        let full = StackList::<i32, 3>::try_from(&[1, 2, 3][..]).expect("Slice should fit.");
+        assert!(full.is_full());
+        assert!(*full == [1, 2, 3]);
+
+        let partial = StackList::<i32, 3>::try_from(&[1, 2][..]).expect("Slice should fit.");
+        assert!(partial.len() == 2);
+        assert!(*partial == [1, 2]);
+
+        let overflow = StackList::<i32, 3>::try_from(&[1, 2, 3, 4][..]);
+        assert!(matches!(overflow, Err(ListError::ListFull(()))));
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let under: StackList<i32, 3> = (1..=2).collect();
+        assert!(*under == [1, 2]);
+
+        let exact: StackList<i32, 3> = (1..=3).collect();
+        assert!(exact.is_full());
+        assert!(*exact == [1, 2, 3]);
+
+        let mut pulled = 0;
+        let over: StackList<i32, 3> = (1..=5).inspect(|_| pulled += 1).collect();
+        assert!(*over == [1, 2, 3]); // Excess items are discarded
+        assert!(pulled == 3); // and never pulled from the iterator
+    }
+
+    #[test]
+    fn test_try_from_iter() {
+        let under = StackList::<i32, 3>::try_from_iter(1..=2).expect("Iterator should fit.");
+        assert!(*under == [1, 2]);
+
+        let exact = StackList::<i32, 3>::try_from_iter(1..=3).expect("Iterator should fit.");
+        assert!(*exact == [1, 2, 3]);
+
+        let over = StackList::<i32, 3>::try_from_iter(1..=5);
+        assert!(matches!(over, Err(ListError::ListFull(4))));
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        list.extend([1, 2]);
+        assert!(*list == [1, 2]);
+
+        list.extend([3, 4, 5, 6]);
+        assert!(*list == [1, 2, 3, 4]); // Stopped once full
+    }
+
+    #[test]
+    fn test_extend_checked() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        assert!(list.extend_checked([1, 2]).is_ok());
+        assert!(list.extend_checked([3, 4]).is_ok());
+        assert!(list.is_full());
+
+        list.clear();
+        assert!(matches!(list.extend_checked([1, 2, 3, 4, 5]), Err(ListError::ListFull(5))));
+        assert!(*list == [1, 2, 3, 4]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize() {
+        let list = StackList::from([1, 2, 3]);
+        assert!(serde_json::to_string(&list).expect("Couldn't serialize list.") == "[1,2,3]");
+
+        let mut partial: StackList<i32, 4> = StackList::new();
+        partial.push(1).expect("Couldn't push to list.");
+        assert!(serde_json::to_string(&partial).expect("Couldn't serialize list.") == "[1]");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize() {
+        let list = StackList::from([1, 2, 3]);
+        let json = serde_json::to_string(&list).expect("Couldn't serialize list.");
+        let round_tripped: StackList<i32, 3> = serde_json::from_str(&json).expect("Couldn't deserialize list.");
+        assert!(round_tripped == list);
+
+        let partial: StackList<i32, 3> = serde_json::from_str("[1]").expect("Couldn't deserialize list.");
+        assert!(*partial == [1]);
+
+        let error = serde_json::from_str::<StackList<i32, 3>>("[1,2,3,4]").unwrap_err();
+        assert!(error.to_string().contains("expected a sequence of at most 3 items"));
+    }
+
+    #[test]
+    fn test_const_new() {
+        const CONST_LIST: StackList<i32, 4> = StackList::new();
+        static STATIC_LIST: StackList<i32, 4> = StackList::new();
+        let list: StackList<i32, 4> = StackList::new();
+
+        assert!(CONST_LIST.is_empty());
+        assert!(STATIC_LIST.is_empty());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_list_full_returns_item() {
+        let mut list: StackList<String, 1> = StackList::new();
+
+        list.push(String::from("kept")).expect("Couldn't push to list.");
+
+        match list.push(String::from("rejected")) {
+            Err(ListError::ListFull(item)) => assert!(item == "rejected"),
+            Ok(()) => panic!("Pushed past capacity.")
+        }
+        assert!(*list == ["kept"]);
+    }
+
+    #[test]
+    fn test_push_unchecked() {
+        let mut list: StackList<i32, 3> = StackList::new();
+
+        while !list.is_full() {
+            unsafe { list.push_unchecked(list.len() as i32) };
+        }
+
+        assert!(*list == [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let drops = Cell::new(0);
+        let mut list: StackList<DropCounter, 5> = StackList::new();
+
+        for _ in 0..5 {
+            list.push(DropCounter(&drops)).expect("Couldn't push to list.");
+        }
+
+        list.truncate(3);
+        assert!(list.len() == 3);
+        assert!(drops.get() == 2);
+
+        list.truncate(4); // Longer than the list, so nothing happens
+        assert!(list.len() == 3);
+        assert!(drops.get() == 2);
+
+        list.truncate(0);
+        assert!(list.is_empty());
+        assert!(drops.get() == 5);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut list: StackList<String, 4> = StackList::new();
+
+        list.insert(0, String::from("b")).expect("Couldn't insert into list."); // Into an empty list
+        list.insert(0, String::from("a")).expect("Couldn't insert into list."); // At the front
+        list.insert(2, String::from("d")).expect("Couldn't insert into list."); // At the end
+        list.insert(2, String::from("c")).expect("Couldn't insert into list."); // In the middle
+        assert!(*list == ["a", "b", "c", "d"]);
+
+        match list.insert(1, String::from("e")) {
+            Err(ListError::ListFull(item)) => assert!(item == "e"),
+            Ok(()) => panic!("Inserted past capacity.")
+        }
+        assert!(*list == ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+    fn test_insert_out_of_bounds() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        list.push(1).expect("Couldn't push to list.");
+        let _ = list.insert(2, 2);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut list = StackList::from([
+            String::from("a"), String::from("b"), String::from("c"), String::from("d"), String::from("e")
+        ]);
+
+        assert!(list.remove(0) == "a"); // From the front
+        assert!(*list == ["b", "c", "d", "e"]);
+        assert!(list.remove(1) == "c"); // From the middle
+        assert!(*list == ["b", "d", "e"]);
+        assert!(list.remove(2) == "e"); // From the end
+        assert!(*list == ["b", "d"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "removal index (is 1) should be < len (is 1)")]
+    fn test_remove_out_of_bounds() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        list.push(1).expect("Couldn't push to list.");
+        list.remove(1);
+    }
+
+    #[test]
+    fn test_swap_remove() {
+        let mut list = StackList::from([1, 2, 3, 4]);
+
+        assert!(list.swap_remove(1) == 2);
+        assert!(*list == [1, 4, 3]); // The last item filled the gap
+        assert!(list.swap_remove(2) == 3); // Removing the last item just pops it
+        assert!(*list == [1, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "swap_remove index (is 0) should be < len (is 0)")]
+    fn test_swap_remove_out_of_bounds() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        list.swap_remove(0);
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut list = StackList::from([1, 2, 3]);
+
+        list.swap(0, 2);
+        assert!(*list == [3, 2, 1]);
+        list.swap(1, 1);
+        assert!(*list == [3, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_swap_out_of_bounds() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        list.swap(0, 2); // Within capacity, but past the written region
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut list = StackList::from([1, 2, 3, 4, 5, 6]);
+
+        list.retain(|i| i % 2 == 0);
+        assert!(*list == [2, 4, 6]);
+
+        let drops = Cell::new(0);
+        let mut list: StackList<(i32, DropCounter), 6> = StackList::new();
+        for i in 0..6 {
+            list.push((i, DropCounter(&drops))).expect("Couldn't push to list.");
+        }
+
+        list.retain(|(i, _)| *i % 3 != 0);
+        assert!(drops.get() == 2);
+        assert!(list.iter().map(|(i, _)| *i).eq([1, 2, 4, 5]));
+
+        drop(list);
+        assert!(drops.get() == 6);
+    }
+
+    #[test]
+    fn test_contains() {
+        let mut list: StackList<i32, 4> = StackList::new();
+        assert!(!list.contains(&0));
+
+        list.push(1).expect("Couldn't push to list.");
+        list.push(2).expect("Couldn't push to list.");
+        assert!(list.contains(&1));
+        assert!(list.contains(&2));
+        assert!(!list.contains(&3));
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut even = StackList::from([1, 2, 3, 4]);
+        even.reverse();
+        assert!(even.iter().copied().eq([4, 3, 2, 1]));
+
+        let mut odd: StackList<i32, 4> = StackList::new();
+        odd.extend([1, 2, 3]);
+        odd.reverse();
+        assert!(odd.iter().copied().eq([3, 2, 1]));
+        assert!(odd.len() == 3);
+
+        let mut empty: StackList<i32, 4> = StackList::new();
+        empty.reverse();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_rotate() {
+        for len in 0..=5 {
+            for n in 0..=len {
+                let mut list: StackList<usize, 5> = (0..len).collect();
+                let mut expected: Vec<usize> = (0..len).collect();
+
+                list.rotate_left(n);
+                expected.rotate_left(n);
+                assert!(*list == *expected);
+
+                list.rotate_right(n);
+                expected.rotate_right(n);
+                assert!(*list == *expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotate_out_of_bounds() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        list.push(1).expect("Couldn't push to list.");
+        list.rotate_left(2);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sort() {
+        let mut list = StackList::from([3, 1, 4, 1, 5]);
+        list.sort();
+        assert!(*list == [1, 1, 3, 4, 5]);
+
+        let mut list = StackList::from([3, 1, 4, 1, 5]);
+        list.sort_by(|a, b| b.cmp(a));
+        assert!(*list == [5, 4, 3, 1, 1]);
+
+        // Items with equal keys keep their relative order.
+        let mut list = StackList::from([(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]);
+        list.sort_by_key(|(score, _)| *score);
+        assert!(*list == [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+    }
+
+    #[test]
+    fn test_sort_unstable() {
+        let mut list = StackList::from([3, 1, 4, 1, 5]);
+        list.sort_unstable();
+        assert!(*list == [1, 1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_binary_search() {
+        let mut list: StackList<i32, 8> = StackList::new();
+        list.extend([1, 3, 5, 7]);
+
+        assert!(list.binary_search(&1) == Ok(0));
+        assert!(list.binary_search(&5) == Ok(2));
+        assert!(list.binary_search(&0) == Err(0));
+        assert!(list.binary_search(&4) == Err(2));
+        assert!(list.binary_search(&8) == Err(4)); // Insertion point is at the end of the written region
+
+        assert!(list.binary_search_by(|i| i.cmp(&7)) == Ok(3));
+        assert!(list.binary_search_by(|i| i.cmp(&6)) == Err(3));
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut list = StackList::from([1, 1, 2, 3, 3, 3, 1]);
+        list.dedup();
+        assert!(*list == [1, 2, 3, 1]);
+
+        let mut list = StackList::from([10, 11, 20, 35, 31, 12]);
+        list.dedup_by_key(|i| i / 10);
+        assert!(*list == [10, 20, 35, 12]);
+
+        let drops = Cell::new(0);
+        let mut list: StackList<(i32, DropCounter), 7> = StackList::new();
+        for i in [1, 1, 2, 3, 3, 3, 1] {
+            list.push((i, DropCounter(&drops))).expect("Couldn't push to list.");
+        }
+
+        list.dedup_by_key(|(i, _)| *i);
+        assert!(drops.get() == 3);
+        assert!(list.iter().map(|(i, _)| *i).eq([1, 2, 3, 1]));
+
+        drop(list);
+        assert!(drops.get() == 7);
+    }
+
+    #[test]
+    fn test_drain() {
+        let mut list = StackList::from([1, 2, 3, 4, 5]);
+
+        let drained: Vec<i32> = list.drain(1..3).collect();
+        assert!(drained == [2, 3]);
+        assert!(*list == [1, 4, 5]);
+
+        let drained: Vec<i32> = list.drain(..).collect();
+        assert!(drained == [1, 4, 5]);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_drain_partial() {
+        let mut list = StackList::from([1, 2, 3, 4, 5]);
+
+        let mut drain = list.drain(1..=3);
+        assert!(drain.len() == 3);
+        assert!(drain.next() == Some(2));
+        assert!(drain.next_back() == Some(4));
+        drop(drain);
+
+        assert!(*list == [1, 5]); // The unyielded 3 is gone too
+    }
+
+    #[test]
+    fn test_drain_drops() {
+        let drops = Cell::new(0);
+        let mut list: StackList<DropCounter, 5> = StackList::new();
+        for _ in 0..5 {
+            list.push(DropCounter(&drops)).expect("Couldn't push to list.");
+        }
+
+        let mut drain = list.drain(1..4);
+        drop(drain.next());
+        assert!(drops.get() == 1);
+        drop(drain);
+        assert!(drops.get() == 3); // The two unyielded items were dropped with the iterator
+        assert!(list.len() == 2);
+
+        drop(list);
+        assert!(drops.get() == 5);
+    }
+
+    #[test]
+    fn test_drain_front() {
+        let mut list = StackList::from([1, 2, 3, 4, 5]);
+        assert!(list.drain_front(2).eq([1, 2]));
+        assert!(list == [3, 4, 5]);
+
+        let mut list = StackList::from([1, 2, 3]);
+        assert!(list.drain_front(3).eq([1, 2, 3]));
+        assert!(list.is_empty());
+
+        let mut list = StackList::from([1, 2, 3]);
+        assert!(list.drain_front(10).eq([1, 2, 3]));
+        assert!(list.is_empty());
+        assert!(list.drain_front(1).next().is_none());
+    }
+
+    #[test]
+    fn test_drain_front_early_drop() {
+        let drops = Cell::new(0);
+        let mut list: StackList<DropCounter, 5> = StackList::new();
+        for _ in 0..5 {
+            list.push(DropCounter(&drops)).expect("Couldn't push to list.");
+        }
+
+        let mut drain = list.drain_front(3);
+        drop(drain.next());
+        drop(drain);
+        assert!(drops.get() == 3);
+        assert!(list.len() == 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "drain range end (is 3) should be <= len (is 2)")]
+    fn test_drain_out_of_bounds() {
+        let mut list: StackList<i32, 4> = StackList::new();
+        list.extend([1, 2]);
+
+        list.drain(1..3);
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut list = StackList::from([1, 2, 3, 4]);
+
+        let tail = list.split_off(4);
+        assert!(*list == [1, 2, 3, 4]);
+        assert!(tail.is_empty());
+
+        let tail = list.split_off(2);
+        assert!(*list == [1, 2]);
+        assert!(*tail == [3, 4]);
+
+        let tail = list.split_off(0);
+        assert!(list.is_empty());
+        assert!(*tail == [1, 2]);
+
+        let drops = Cell::new(0);
+        let mut list: StackList<DropCounter, 4> = StackList::new();
+        for _ in 0..4 {
+            list.push(DropCounter(&drops)).expect("Couldn't push to list.");
+        }
+        let tail = list.split_off(1);
+        drop(tail);
+        assert!(drops.get() == 3);
+        drop(list);
+        assert!(drops.get() == 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "split_off index (is 2) should be <= len (is 1)")]
+    fn test_split_off_out_of_bounds() {
+        let mut list: StackList<i32, 4> = StackList::new();
+        list.push(1).expect("Couldn't push to list.");
+
+        list.split_off(2);
+    }
+
+    #[test]
+    fn test_extend_from_slice() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        assert!(list.extend_from_slice(&[1, 2]).is_ok());
+        assert!(list.extend_from_slice(&[3, 4, 5]).is_err());
+        assert!(*list == [1, 2]); // Nothing from the failed slice was pushed
+
+        assert!(list.extend_from_slice(&[3, 4]).is_ok());
+        assert!(*list == [1, 2, 3, 4]);
+        assert!(list.extend_from_slice(&[]).is_ok());
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut list: StackList<i32, 4> = StackList::new();
+        list.fill(7);
+        assert!(list.is_full());
+        assert!(*list == [7, 7, 7, 7]);
+
+        let mut list: StackList<i32, 4> = StackList::new();
+        list.push(1).expect("Couldn't push to list.");
+        list.fill(0);
+        assert!(*list == [1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_fill_with() {
+        let mut next = 0;
+        let mut list: StackList<i32, 4> = StackList::new();
+        list.fill_with(|| { next += 1; next });
+        assert!(list.is_full());
+        assert!(*list == [1, 2, 3, 4]);
+
+        let mut list: StackList<i32, 4> = StackList::new();
+        list.extend([9, 9]);
+        list.fill_with(Default::default);
+        assert!(*list == [9, 9, 0, 0]);
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut list: StackList<i32, 4> = StackList::new();
+        list.push(1).expect("Couldn't push to list.");
+
+        list.resize(3, 0).expect("Couldn't resize list.");
+        assert!(*list == [1, 0, 0]); // Grown
+        list.resize(3, 5).expect("Couldn't resize list.");
+        assert!(*list == [1, 0, 0]); // No change
+        list.resize(1, 5).expect("Couldn't resize list.");
+        assert!(*list == [1]); // Shrunk
+
+        assert!(matches!(list.resize(5, 9), Err(ListError::ListFull(9))));
+        assert!(*list == [1]);
+    }
+
+    #[test]
+    fn test_resize_with() {
+        let mut next = 0;
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        list.resize_with(2, || { next += 1; next }).expect("Couldn't resize list.");
+        assert!(*list == [1, 2]);
+        list.resize_with(4, || { next += 1; next }).expect("Couldn't resize list.");
+        assert!(*list == [1, 2, 3, 4]);
+        list.resize_with(0, || { next += 1; next }).expect("Couldn't resize list.");
+        assert!(list.is_empty());
+
+        assert!(list.resize_with(5, || 0).is_err());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_to_vec() {
+        let list = StackList::from([String::from("a"), String::from("b")]);
+
+        let vec = list.to_vec();
+        assert!(vec.len() == list.len());
+        assert!(vec == *list);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_into_vec() {
+        let mut list: StackList<String, 4> = StackList::new();
+        list.push(String::from("a")).expect("Couldn't push to list.");
+        list.push(String::from("b")).expect("Couldn't push to list.");
+
+        let vec = list.into_vec();
+        assert!(vec.len() == 2);
+        assert!(vec == ["a", "b"]);
+
+        let drops = Cell::new(0);
+        let list = StackList::from([DropCounter(&drops), DropCounter(&drops)]);
+        let vec = list.into_vec();
+        assert!(drops.get() == 0);
+        drop(vec);
+        assert!(drops.get() == 2); // Each item was dropped exactly once, by the Vec
+    }
+
+    #[test]
+    fn test_concat() {
+        let first: StackList<i32, 3> = StackList::from([1, 2, 3]);
+        let mut second: StackList<i32, 4> = StackList::new();
+        second.extend([4, 5]);
+
+        let joined: StackList<i32, 7> = first.concat(second);
+        assert!(joined.len() == 5);
+        assert!(*joined == [1, 2, 3, 4, 5]);
+
+        let drops = Cell::new(0);
+        let first = StackList::from([DropCounter(&drops)]);
+        let second = StackList::from([DropCounter(&drops), DropCounter(&drops)]);
+        let joined = first.concat::<2, 3>(second);
+        assert!(drops.get() == 0);
+        drop(joined);
+        assert!(drops.get() == 3);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut list: StackList<String, 4> = StackList::new();
+        list.push(String::from("a")).expect("Couldn't push to list.");
+        list.push(String::from("b")).expect("Couldn't push to list.");
+
+        let mut other = StackList::from([String::from("c"), String::from("d"), String::from("e")]);
+        assert!(list.append(&mut other).is_err()); // Not enough room
+        assert!(*list == ["a", "b"]);
+        assert!(*other == ["c", "d", "e"]);
+
+        other.pop();
+        assert!(list.append(&mut other).is_ok()); // Exactly enough room
+        assert!(*list == ["a", "b", "c", "d"]);
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn test_as_ref() {
+        fn checksum<B: AsRef<[u8]>>(bytes: B) -> u32 {
+            bytes.as_ref().iter().map(|&b| b as u32).sum()
+        }
+
+        fn zero<B: AsMut<[u8]>>(mut bytes: B) {
+            bytes.as_mut().fill(0);
+        }
+
+        let mut list: StackList<u8, 4> = StackList::new();
+        list.extend([1, 2, 3]);
+        assert!(checksum(&list) == 6);
+
+        zero(&mut list);
+        assert!(*list == [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_windows() {
+        let list = StackList::from([1, 2, 3]);
+
+        assert!(list.windows(1).eq([&[1][..], &[2], &[3]]));
+        assert!(list.windows(2).eq([&[1, 2][..], &[2, 3]]));
+        assert!(list.windows(3).eq([&[1, 2, 3][..]]));
+        assert!(list.windows(4).next().is_none());
+
+        assert!(list.windows(2).len() == 2);
+        assert!(list.windows(2).rev().eq([&[2, 3][..], &[1, 2]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_windows_zero() {
+        let list = StackList::from([1, 2, 3]);
+
+        let _ = list.windows(0);
+    }
+
+    #[test]
+    fn test_chunks() {
+        let even = StackList::from([1, 2, 3, 4]);
+        assert!(even.chunks(2).eq([&[1, 2][..], &[3, 4]]));
+        assert!(even.chunks_exact(2).eq([&[1, 2][..], &[3, 4]]));
+
+        let uneven = StackList::from([1, 2, 3, 4, 5]);
+        assert!(uneven.chunks(2).eq([&[1, 2][..], &[3, 4], &[5]]));
+        assert!(uneven.chunks_exact(2).eq([&[1, 2][..], &[3, 4]])); // The short final chunk is skipped
+        assert!(uneven.chunks_exact(2).remainder() == [5]);
+        assert!(even.chunks_exact(2).remainder().is_empty());
+
+        assert!(uneven.chunks(2).len() == 3);
+        assert!(uneven.chunks(2).next_back() == Some(&[5][..]));
+        assert!(uneven.chunks_exact(2).rev().eq([&[3, 4][..], &[1, 2]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chunks_zero() {
+        let list = StackList::from([1, 2, 3]);
+
+        let _ = list.chunks(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chunks_exact_zero() {
+        let list = StackList::from([1, 2, 3]);
+
+        let _ = list.chunks_exact(0);
+    }
+
+    #[test]
+    fn test_split_first_last() {
+        let list = StackList::from([1, 2, 3]);
+
+        assert!(list.split_first() == Some((&1, &[2, 3][..])));
+        assert!(list.split_last() == Some((&3, &[1, 2][..])));
+
+        let empty: StackList<i32, 3> = StackList::new();
+        assert!(empty.split_first().is_none());
+        assert!(empty.split_last().is_none());
+    }
+
+    #[test]
+    fn test_pop_if() {
+        let mut list = StackList::from([1, 2]);
+
+        assert!(list.pop_if(|i| *i == 1).is_none());
+        assert!(list.len() == 2); // Predicate failed, so nothing was removed
+
+        assert!(list.pop_if(|i| *i == 2) == Some(2));
+        assert!(*list == [1]);
+
+        list.clear();
+        assert!(list.pop_if(|_| true).is_none());
+    }
+
+    #[test]
+    fn test_first_last() {
+        let mut list: StackList<i32, 4> = StackList::new();
+
+        assert!(list.first().is_none());
+        assert!(list.first_mut().is_none());
+        assert!(list.last().is_none());
+        assert!(list.last_mut().is_none());
+
+        list.extend([1, 2, 3]);
+        assert!(list.first() == Some(&1));
+        assert!(list.last() == Some(&3));
+
+        *list.first_mut().expect("List was empty.") = 10;
+        *list.last_mut().expect("List was empty.") = 30;
+        assert!(*list == [10, 2, 30]);
+    }
+
+    #[test]
+    fn test_retain_mut() {
+        let drops = Cell::new(0);
+        let mut list: StackList<(i32, DropCounter), 5> = StackList::new();
+        for i in 0..5 {
+            list.push((i, DropCounter(&drops))).expect("Couldn't push to list.");
+        }
+
+        list.retain_mut(|(i, _)| {
+            *i *= 10;
+            *i != 20
+        });
+        assert!(drops.get() == 1);
+        assert!(list.iter().map(|(i, _)| *i).eq([0, 10, 30, 40]));
+
+        drop(list);
+        assert!(drops.get() == 5);
+    }
+
+    #[test]
+    fn test_push_overwrite() {
+        let mut list: StackList<i32, 3> = StackList::new();
+
+        assert!(list.push_overwrite(1).is_none());
+        assert!(list.push_overwrite(2).is_none());
+        assert!(list.push_overwrite(3).is_none());
+        assert!(list.push_overwrite(4) == Some(1));
+        assert!(list.push_overwrite(5) == Some(2));
+        assert!(*list == [3, 4, 5]); // The window slid along
+
+        let mut empty: StackList<i32, 0> = StackList::new();
+        assert!(empty.push_overwrite(1) == Some(1));
+    }
+
+    #[test]
+    fn test_push_overwrite_drops() {
+        let drops = Cell::new(0);
+        let mut list: StackList<DropCounter, 2> = StackList::new();
+
+        for _ in 0..5 {
+            drop(list.push_overwrite(DropCounter(&drops)));
+        }
+        assert!(drops.get() == 3); // Evicted items are handed back rather than leaked
+
+        drop(list);
+        assert!(drops.get() == 5);
+    }
+
+    #[test]
+    fn test_copy_from() {
+        let source = StackList::from([1u64, 2, 3, 4]);
+        let mut list: StackList<u64, 4> = StackList::new();
+        list.push(9).expect("Couldn't push to list.");
+
+        list.copy_from(&source);
+        assert!(list == source);
+
+        let mut shorter: StackList<u64, 4> = StackList::new();
+        shorter.push(5).expect("Couldn't push to list.");
+        list.copy_from(&shorter);
+        assert!(*list == [5]); // Old items past the new length are gone
+    }
+
+    #[test]
+    fn test_into_iter_ref() {
+        let mut list = StackList::from([1, 2, 3]);
+
+        let mut expected = 0;
+        for i in &list {
+            expected += 1;
+            assert!(*i == expected);
+        }
+
+        for i in &mut list {
+            *i += 10;
+        }
+        assert!(*list == [11, 12, 13]);
+    }
+
+    #[test]
+    fn test_into_iter_front() {
+        let list = StackList::from([1, 2, 3]);
+        assert!(list.clone().into_iter_front().eq([1, 2, 3]));
+        assert!(list.into_iter().eq([3, 2, 1]));
+
+        let drops = Cell::new(0);
+        let list = StackList::from([DropCounter(&drops), DropCounter(&drops), DropCounter(&drops)]);
+        let mut iter = list.into_iter_front();
+        assert!(iter.len() == 3);
+        drop(iter.next());
+        assert!(drops.get() == 1);
+        drop(iter);
+        assert!(drops.get() == 3); // The unconsumed items were dropped exactly once
+    }
+
+    #[test]
+    fn test_into_iter_partial_drop() {
+        let drops = Cell::new(0);
+        let list = StackList::from([DropCounter(&drops), DropCounter(&drops), DropCounter(&drops)]);
+
+        let mut iter = list.into_iter();
+        drop(iter.next());
+        drop(iter.next());
+        assert!(drops.get() == 2);
+
+        drop(iter);
+        assert!(drops.get() == 3);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_error() {
+        fn fill() -> Result<(), Box<dyn std::error::Error>> {
+            let mut list: StackList<i32, 1> = StackList::new();
+            list.push(1)?;
+            list.push(2)?;
+
+            Ok(())
+        }
+
+        let error = fill().unwrap_err();
+        assert!(error.to_string() == "The list is full.");
+    }
+
+    #[test]
+    fn test_error_eq() {
+        let mut list: StackList<i32, 1> = StackList::new();
+        list.push(1).expect("Couldn't push to list.");
+
+        let error = list.push(2).unwrap_err();
+        assert_eq!(error, ListError::ListFull(2));
+        assert_eq!(error.clone(), error);
+        assert_ne!(error, ListError::ListFull(3));
+        assert_eq!(StackList::<i32, 1>::try_from(&[1, 2][..]), Err(ListError::ListFull(())));
+    }
+
+    #[test]
+    fn test_eq_other_types() {
+        let mut list: StackList<i32, 4> = StackList::new();
+        list.extend([1, 2, 3]);
+
+        assert_eq!(list, [1, 2, 3]);
+        assert!(list != [1, 2]);
+        assert!(list != [1, 2, 3, 4]);
+
+        assert!(list == &[1, 2, 3][..]);
+        assert!(list != &[1, 2, 4][..]);
+        assert!(list != &[][..]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_eq_vec() {
+        let mut list: StackList<i32, 4> = StackList::new();
+        list.extend([1, 2, 3]);
+
+        assert!(list == vec![1, 2, 3]);
+        assert!(list != vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_eq_other_capacity() {
+        let mut small: StackList<i32, 4> = StackList::new();
+        let mut large: StackList<i32, 8> = StackList::new();
+        small.extend([1, 2, 3]);
+        large.extend([1, 2, 3]);
+
+        assert!(small == large);
+        assert!(large == small);
+
+        large.push(4).expect("Couldn't push to list.");
+        assert!(small != large);
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let mut list: StackList<i32, 5> = StackList::new();
+
+        assert!(list.insert_sorted(5) == Ok(0)); // Into an empty list
+        assert!(list.insert_sorted(1) == Ok(0)); // At the front
+        assert!(list.insert_sorted(9) == Ok(2)); // At the end
+        assert!(list.insert_sorted(7) == Ok(2)); // In the middle
+        assert!(list.insert_sorted(5) == Ok(2)); // After the equal item
+        assert!(*list == [1, 5, 5, 7, 9]);
+
+        assert!(list.insert_sorted(3) == Err(ListError::ListFull(3)));
+        assert!(*list == [1, 5, 5, 7, 9]);
+    }
+
+    #[test]
+    fn test_peek_at() {
+        let list = StackList::from([1, 2, 3]);
+
+        assert!(list.peek_at(0) == Some(&3));
+        assert!(list.peek_at(1) == Some(&2));
+        assert!(list.peek_at(2) == Some(&1)); // The bottom
+        assert!(list.peek_at(3).is_none());
+        assert!(list.peek_at(usize::MAX).is_none());
+    }
+
+    #[test]
+    fn test_get_disjoint_mut() {
+        let mut list: StackList<i32, 4> = StackList::new();
+        list.extend([1, 2, 3]);
+
+        let (a, b) = list.get_disjoint_mut(0, 2).expect("Indices should be valid.");
+        mem::swap(a, b);
+        assert!(*list == [3, 2, 1]);
+
+        let (a, b) = list.get_disjoint_mut(1, 0).expect("Indices should be valid.");
+        *a += 10;
+        *b += 20;
+        assert!(*list == [23, 12, 1]);
+
+        assert!(list.get_disjoint_mut(1, 1).is_none());
+        assert!(list.get_disjoint_mut(0, 3).is_none()); // Within capacity, but past the written region
+        assert!(list.get_disjoint_mut(4, 0).is_none());
+    }
+
+    #[test]
+    fn test_split_at() {
+        let mut list: StackList<i32, 4> = StackList::new();
+        list.extend([1, 2, 3]);
+
+        assert!(list.split_at(0) == (&[][..], &[1, 2, 3][..]));
+        assert!(list.split_at(1) == (&[1][..], &[2, 3][..]));
+        assert!(list.split_at(3) == (&[1, 2, 3][..], &[][..]));
+
+        let (front, back) = list.split_at_mut(2);
+        front[0] = 10;
+        back[0] = 30;
+        assert!(*list == [10, 2, 30]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_at_out_of_bounds() {
+        let mut list: StackList<i32, 4> = StackList::new();
+        list.extend([1, 2, 3]);
+
+        list.split_at(4); // Within capacity, but past the written region
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let inputs: [&[u8]; 4] = [&[], &[0], &[255; 64], &[3, 1, 2, 3, 4, 5, 6, 7, 8]];
+        for bytes in inputs {
+            let mut u = Unstructured::new(bytes);
+            let list = StackList::<u8, 4>::arbitrary(&mut u).expect("Couldn't generate list.");
+            assert!(list.len() <= list.capacity());
+        }
+    }
+
+    #[test]
+    fn test_map() {
+        let mut list: StackList<i32, 4> = StackList::new();
+        list.extend([1, 2, 3]);
+
+        let mapped: StackList<String, 4> = list.map(|i| i.to_string());
+        assert!(mapped.len() == 3);
+        assert!(*mapped == ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_map_panic() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let drops = Cell::new(0);
+        let list = StackList::from([0, 1, 2, 3].map(|i| (i, DropCounter(&drops))));
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            list.map(|(i, counter)| {
+                if i == 2 {
+                    panic!("Map failed.");
+                }
+                counter
+            })
+        }));
+        assert!(result.is_err());
+        assert!(drops.get() == 4); // Mapped, unmapped and the item being mapped were all dropped
+    }
+
+    #[test]
+    fn test_filter_map() {
+        let list = StackList::from([1, 2, 3, 4]);
+
+        let evens: StackList<String, 4> = list.filter_map(|i| (i % 2 == 0).then(|| i.to_string()));
+        assert!(*evens == ["2", "4"]);
+    }
+
+    #[test]
+    fn test_from_filter_map() {
+        let under = StackList::<i32, 4>::from_filter_map(1..=5, |i| (i % 2 == 1).then_some(i * 10));
+        assert!(*under == [10, 30, 50]);
+
+        let mut seen = 0;
+        let over = StackList::<i32, 2>::from_filter_map(1..=10, |i| {
+            seen += 1;
+            (i % 2 == 1).then_some(i)
+        });
+        assert!(*over == [1, 3]); // Saturated at capacity
+        assert!(seen == 3); // and stopped pulling once full
+    }
+
+    #[test]
+    fn test_zip() {
+        let moves = StackList::from(["e2e4", "d2d4"]);
+        let scores = StackList::from([30, 25]);
+
+        let zipped = moves.zip(scores);
+        assert!(*zipped == [("e2e4", 30), ("d2d4", 25)]);
+
+        let drops = Cell::new(0);
+        let short = StackList::from([1, 2]);
+        let long = StackList::from([0; 4].map(|_| DropCounter(&drops)));
+
+        let zipped = short.zip(long);
+        assert!(zipped.len() == 2);
+        assert!(drops.get() == 2); // The two unpaired items were dropped
+        drop(zipped);
+        assert!(drops.get() == 4);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let list: StackList<usize, 5> = StackList::from_fn(|i| i);
+        assert!(list.is_full());
+        assert!(list == [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_from_fn_panic() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let drops = Cell::new(0);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            StackList::<DropCounter, 5>::from_fn(|i| {
+                if i == 3 {
+                    panic!("Couldn't create item.");
+                }
+                DropCounter(&drops)
+            })
+        }));
+
+        assert!(result.is_err());
+        assert!(drops.get() == 3);
+    }
+
+    #[test]
+    fn test_filled() {
+        let list = StackList::<u8, 4>::filled(0);
+        assert!(list.is_full());
+        assert!(list == [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_filled_panic() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let clones = Cell::new(0);
+        let drops = Cell::new(0);
+        let value = PanicOnClone { clones: &clones, drops: &drops };
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| StackList::<PanicOnClone, 4>::filled(value)));
+        assert!(result.is_err());
+        assert!(drops.get() == 2); // The one successful clone and the original value
+    }
+
+    #[test]
+    fn test_borrow_slice_key() {
+        use std::collections::HashMap;
+
+        let mut map: HashMap<StackList<u8, 4>, &str> = HashMap::new();
+        map.insert(StackList::from([1, 2, 3, 4]), "full");
+        map.insert([5].as_slice().try_into().expect("Couldn't convert slice."), "short");
+
+        let key: &[u8] = &[1, 2, 3, 4];
+        assert!(map.get(key) == Some(&"full"));
+        assert!(map.get([5].as_slice()) == Some(&"short"));
+        assert!(!map.contains_key([1, 2].as_slice()));
+    }
+
+    #[test]
+    fn test_borrow_mut() {
+        let mut list: StackList<u8, 4> = [3, 1, 2].as_slice().try_into().expect("Couldn't convert slice.");
+        let slice: &mut [u8] = list.borrow_mut();
+        slice.sort_unstable();
+        assert!(list == [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_position() {
+        let list: StackList<i32, 6> = [4, 7, 2, 7].into_iter().collect();
+
+        assert!(list.position(|&x| x == 7) == Some(1));
+        assert!(list.position(|&x| x < 3) == Some(2));
+        assert!(list.position(|&x| x == 9).is_none());
+        assert!(StackList::<i32, 6>::new().position(|_| true).is_none());
+    }
+
+    #[test]
+    fn test_count() {
+        let list: StackList<i32, 6> = [4, 7, 2, 7, 1].into_iter().collect();
+
+        assert!(list.count(|&x| x == 7) == 2);
+        assert!(list.count(|&x| x % 2 == 0) == 2);
+        assert!(list.count(|_| true) == 5);
+        assert!(list.count(|&x| x > 10) == 0);
+        assert!(StackList::<i32, 6>::new().count(|_| true) == 0);
+    }
+
+    #[test]
+    fn test_clear_fast() {
+        let mut list: StackList<u8, 4096> = StackList::filled(7);
+
+        list.clear_fast();
+        assert!(list.is_empty());
+        assert!(list.pop().is_none());
+
+        list.push(1).expect("Couldn't push to list.");
+        assert!(list == [1]);
+    }
+
+    #[test]
+    fn test_try_push() {
+        let mut list: StackList<String, 2> = StackList::new();
+
+        assert!(list.try_push("a".to_string()).is_ok());
+        assert!(list.try_push("b".to_string()).is_ok());
+        assert!(list.try_push("c".to_string()) == Err("c".to_string()));
+        assert!(list == ["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn test_push_all() {
+        let mut list: StackList<i32, 5> = StackList::new();
+
+        assert!(list.push_all([1, 2, 3]) == 3);
+        assert!(list == [1, 2, 3]);
+        assert!(list.push_all(iter::empty()) == 0);
+    }
+
+    #[test]
+    fn test_push_all_overflow() {
+        let mut list: StackList<i32, 5> = StackList::from_iter([1, 2]);
+        let mut source = 10..20;
+
+        assert!(list.push_all(source.by_ref()) == 3);
+        assert!(list == [1, 2, 10, 11, 12]);
+        assert!(source.eq(13..20)); // Nothing past capacity was consumed
+        assert!(list.push_all(30..40) == 0);
+    }
+
+    #[test]
+    fn test_const_queries() {
+        // Queried through references, as a const list by value would need its destructor run at compile time.
+        const EMPTY_LIST: &StackList<i32, 4> = &StackList::new();
+        const _: () = assert!(EMPTY_LIST.is_empty());
+        const _: () = assert!(!EMPTY_LIST.is_full());
+        const _: () = assert!(EMPTY_LIST.len() < EMPTY_LIST.capacity());
+        const _: () = assert!(EMPTY_LIST.capacity() == 4);
+
+        const NO_CAPACITY: &StackList<i32, 0> = &StackList::new();
+        const _: () = assert!(NO_CAPACITY.is_full());
+    }
+
+    #[test]
+    fn test_try_into_capacity() {
+        let mut list: StackList<String, 8> = StackList::new();
+        list.extend(["a", "b", "c"].map(String::from));
+
+        let tight: StackList<String, 3> = list.try_into_capacity().expect("Couldn't shrink list.");
+        assert!(tight.is_full());
+        assert!(tight == ["a", "b", "c"].map(String::from));
+
+        let loose: StackList<String, 5> = tight.try_into_capacity().expect("Couldn't grow list.");
+        assert!(loose.len() == 3);
+    }
+
+    #[test]
+    fn test_try_into_capacity_too_small() {
+        let list = StackList::from([1, 2, 3]);
+
+        let result: Result<StackList<i32, 2>, _> = list.try_into_capacity();
+        let list = result.expect_err("Shrunk below len.");
+        assert!(list == [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_try_into_capacity_drops() {
+        let drops = Cell::new(0);
+        let mut list: StackList<DropCounter, 8> = StackList::new();
+        for _ in 0..3 {
+            list.push(DropCounter(&drops)).expect("Couldn't push to list.");
+        }
+
+        let moved: StackList<DropCounter, 4> = list.try_into_capacity().ok().expect("Couldn't shrink list.");
+        assert!(drops.get() == 0);
+        drop(moved);
+        assert!(drops.get() == 3);
+    }
+
+    #[test]
+    fn test_indexed() {
+        let list: StackList<char, 5> = ['a', 'b', 'c'].into_iter().collect();
+
+        let indexed = list.indexed();
+        assert!(indexed.len() == list.len());
+        assert!(indexed.size_hint() == (3, Some(3)));
+        assert!(list.indexed().eq([(0, &'a'), (1, &'b'), (2, &'c')]));
+        assert!(list.indexed().next_back() == Some((2, &'c')));
+        assert!(StackList::<char, 5>::new().indexed().len() == 0);
+    }
+
+    #[test]
+    fn test_sort_dedup() {
+        let mut list = StackList::from([3, 1, 2, 3, 1]);
+        list.sort_dedup();
+        assert!(list == [1, 2, 3]);
+
+        let mut empty: StackList<i32, 4> = StackList::new();
+        empty.sort_dedup();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_sort_dedup_drops() {
+        /// Ordered by its value, counting drops in a shared counter.
+        struct Keyed<'a>(i32, &'a Cell<usize>);
+
+        impl PartialEq for Keyed<'_> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for Keyed<'_> {}
+
+        impl PartialOrd for Keyed<'_> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Keyed<'_> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        impl Drop for Keyed<'_> {
+            fn drop(&mut self) {
+                self.1.set(self.1.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let mut list: StackList<Keyed, 5> = [3, 1, 2, 3, 1].into_iter().map(|n| Keyed(n, &drops)).collect();
+
+        list.sort_dedup();
+        assert!(drops.get() == 2); // Each removed duplicate is dropped exactly once
+        assert!(list.iter().map(|k| k.0).eq([1, 2, 3]));
+
+        drop(list);
+        assert!(drops.get() == 5);
+    }
+
+    #[test]
+    fn test_index_of() {
+        let list = StackList::from([5, 3, 5, 8, 5]);
+
+        assert!(list.index_of(&5) == Some(0));
+        assert!(list.last_index_of(&5) == Some(4));
+        assert!(list.index_of(&8) == Some(3));
+        assert!(list.last_index_of(&8) == Some(3));
+        assert!(list.index_of(&9).is_none());
+        assert!(list.last_index_of(&9).is_none());
+    }
+
+    #[test]
+    fn test_remaining_capacity() {
+        let mut list: StackList<i32, 4> = StackList::new();
+        assert!(list.remaining_capacity() == 4);
+        assert!(list.has_room_for(4));
+        assert!(!list.has_room_for(5));
+
+        list.extend([1, 2, 3]);
+        assert!(list.remaining_capacity() == 1);
+        assert!(list.has_room_for(1));
+        assert!(!list.has_room_for(2));
+
+        list.push(4).expect("Couldn't push to list.");
+        assert!(list.remaining_capacity() == 0);
+        assert!(list.has_room_for(0));
+        assert!(!list.has_room_for(1));
+        assert!(!list.has_room_for(usize::MAX));
+    }
+
+    #[test]
+    fn test_copy_from_slice() {
+        let mut list = StackList::<u8, 8>::from_iter([1, 2, 3]);
+
+        list.copy_from_slice(&[7, 8, 9]);
+        assert!(list == [7, 8, 9]);
+
+        let mut empty: StackList<u8, 8> = StackList::new();
+        empty.copy_from_slice(&[]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "source slice length (2) does not match destination slice length (3)")]
+    fn test_copy_from_slice_mismatch() {
+        let mut list = StackList::<u8, 8>::from_iter([1, 2, 3]);
+        list.copy_from_slice(&[7, 8]);
+    }
+
+    #[test]
+    fn test_retain_count() {
+        let mut list = StackList::from([1, 2, 3, 4, 5, 6]);
+
+        assert!(list.retain_count(|&x| x % 3 != 0) == 2);
+        assert!(list == [1, 2, 4, 5]);
+        assert!(list.retain_count(|_| true) == 0);
+        assert!(list.retain_count(|_| false) == 4);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_flatten() {
+        let mut outer: StackList<StackList<i32, 3>, 3> = StackList::new();
+        outer.push(StackList::from([1, 2, 3])).expect("Couldn't push to list.");
+        outer.push(StackList::new()).expect("Couldn't push to list.");
+        outer.push([4, 5].into_iter().collect()).expect("Couldn't push to list.");
+
+        let flat: StackList<i32, 9> = outer.flatten();
+        assert!(flat.len() == 5);
+        assert!(flat == [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_flatten_drops() {
+        let drops = Cell::new(0);
+        let mut outer: StackList<StackList<DropCounter, 2>, 2> = StackList::new();
+        for _ in 0..2 {
+            let mut inner = StackList::new();
+            inner.push(DropCounter(&drops)).expect("Couldn't push to list.");
+            outer.push(inner).expect("Couldn't push to list.");
+        }
+
+        let flat: StackList<DropCounter, 4> = outer.flatten();
+        assert!(drops.get() == 0);
+        drop(flat);
+        assert!(drops.get() == 2);
+    }
+
+    #[test]
+    fn test_as_bytes() {
+        let list: StackList<u32, 3> = [1, 0x0203_0405].into_iter().collect();
+
+        let bytes = unsafe { list.as_bytes() };
+        assert!(bytes.len() == 2 * mem::size_of::<u32>());
+
+        let expected: Vec<u8> = list.iter().flat_map(|n| n.to_ne_bytes()).collect();
+        assert!(bytes == expected);
+    }
+
+    #[test]
+    fn test_extract_if() {
+        let mut list = StackList::from([1, 2, 3, 4, 5, 6]);
+
+        let evens: Vec<i32> = list.extract_if(|x| *x % 2 == 0).collect();
+        assert!(evens == [2, 4, 6]);
+        assert!(list == [1, 3, 5]);
+
+        let none: Vec<i32> = list.extract_if(|_| false).collect();
+        assert!(none.is_empty());
+        assert!(list == [1, 3, 5]);
+    }
+
+    #[test]
+    fn test_extract_if_mutates() {
+        let mut list = StackList::from([1, 2, 3, 4]);
+
+        let extracted: Vec<i32> = list.extract_if(|x| { *x *= 10; *x > 20 }).collect();
+        assert!(extracted == [30, 40]);
+        assert!(list == [10, 20]);
+    }
+
+    #[test]
+    fn test_extract_if_early_drop() {
+        let drops = Cell::new(0);
+        let mut list: StackList<DropCounter, 6> = StackList::new();
+        for _ in 0..6 {
+            list.push(DropCounter(&drops)).expect("Couldn't push to list.");
+        }
+
+        // Extract every other item, but only take the first before abandoning the iterator.
+        let mut call = 0;
+        let mut extract = list.extract_if(|_| { call += 1; call % 2 == 0 });
+        drop(extract.next());
+        assert!(drops.get() == 1);
+        drop(extract);
+
+        assert!(drops.get() == 3); // The other two matches were dropped with the iterator
+        assert!(list.len() == 3);
+
+        drop(list);
+        assert!(drops.get() == 6);
+    }
+
+    #[test]
+    fn test_extract_if_panic() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let mut list = StackList::from([1, 2, 3, 4, 5]);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            list.extract_if(|x| if *x == 4 { panic!("Predicate failed.") } else { *x == 2 }).count()
+        }));
+        assert!(result.is_err());
+        assert!(list == [1, 3, 4, 5]); // Items from the panic onwards are kept
+    }
+
+    #[test]
+    fn test_cycle_push() {
+        let mut history: StackList<i32, 3> = StackList::new();
+
+        assert!(history.cycle_push(1).is_none());
+        assert!(history.cycle_push(2).is_none());
+        assert!(history.cycle_push(3).is_none());
+        assert!(history.cycle_push(4) == Some(1));
+        assert!(history.cycle_push(5) == Some(2));
+        assert!(history.is_full());
+
+        assert!(history.pop() == Some(5));
+        assert!(history.pop() == Some(4));
+        assert!(history.pop() == Some(3));
+        assert!(history.pop().is_none());
+    }
+
+    #[test]
+    fn test_iter_rev_method() {
+        let list = StackList::from([1, 2, 3]);
+        assert!(list.iter_rev().eq(list.iter().rev()));
+
+        let mut iter = list.iter_rev();
+        assert!(iter.len() == 3);
+        assert!(iter.next() == Some(&3));
+        assert!(iter.size_hint() == (2, Some(2)));
+        assert!(iter.next() == Some(&2));
+        assert!(iter.next() == Some(&1));
+        assert!(iter.len() == 0);
+        assert!(iter.next().is_none());
+
+        assert!(StackList::<i32, 3>::new().iter_rev().next().is_none());
+    }
+
+    #[test]
+    fn test_iter_clone() {
+        struct NotClone(i32);
+
+        let list: StackList<NotClone, 4> = (1..=3).map(NotClone).collect();
+        let mut iter = list.iter();
+        iter.next();
+
+        let copy = iter.clone();
+        assert!(iter.map(|n| n.0).eq([2, 3]));
+        assert!(copy.map(|n| n.0).eq([2, 3])); // The clone resumes from the same place
+    }
+
+    #[test]
+    fn test_iter_mut_double_ended() {
+        let mut list = StackList::from([1, 2, 3, 4]);
+
+        for (item, add) in list.iter_mut().rev().zip([10, 20]) {
+            *item += add;
+        }
+        assert!(list == [1, 2, 23, 14]);
+
+        let mut iter = list.iter_mut();
+        assert!(iter.len() == 4);
+        *iter.next().expect("Iterator ended early.") = 0;
+        *iter.next_back().expect("Iterator ended early.") = 0;
+        assert!(iter.len() == 2);
+        assert!(list == [0, 2, 23, 0]);
+    }
+
+    #[test]
+    fn test_error_debug() {
+        struct NoDebug;
+
+        let mut list: StackList<NoDebug, 0> = StackList::new();
+        let error = list.push(NoDebug).unwrap_err();
+
+        assert!(format!("{:?}", error) == "ListFull(..)");
+        assert!(format!("{:?}", ListError::ListFull(2)) == "ListFull(..)");
+    }
+}
@@ -0,0 +1,159 @@
+//! This module describes a small map stored entirely on the stack, backed by
+//! a `StackList` of key/value pairs which is searched linearly. A user must
+//! specify maximum capacity and contained types at compile time. An error is
+//! returned if attempting to insert a new key past the maximum capacity.
+
+use core::{mem, fmt::Debug};
+
+use super::stacklist::{ListError, StackList};
+
+/// A map stored on the stack from keys of type K to values of type V, up to a maximum number of entries S
+pub struct StackMap<K: PartialEq, V, const S: usize> {
+    entries: StackList<(K, V), S>
+}
+
+impl<K: PartialEq, V, const S: usize> StackMap<K, V, S> {
+    /// Insert a value under the given key.
+    /// If the key is already present its value is replaced and the old value is returned.
+    /// If the key is new and the map is full, the pair is handed back inside the error.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, ListError<(K, V)>> {
+        if let Some(existing) = self.get_mut(&key) {
+            return Ok(Some(mem::replace(existing, value)))
+        }
+
+        self.entries.push((key, value))?;
+
+        Ok(None)
+    }
+
+    /// Return a reference to the value stored under the given key. None if not present.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Return a mutable reference to the value stored under the given key. None if not present.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.entries.iter_mut().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Returns true if the given key is present in the map.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Remove the given key from the map, returning its value. None if not present.
+    /// The remaining entries keep their insertion order.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.entries.iter().position(|(k, _)| k == key)?;
+
+        Some(self.entries.remove(index).1)
+    }
+
+    /// Returns the number of entries currently in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the maximum number of entries the map can hold.
+    pub const fn capacity(&self) -> usize {
+        S
+    }
+
+    /// Returns true if the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns true if the map is full.
+    pub fn is_full(&self) -> bool {
+        self.entries.is_full()
+    }
+
+    /// Drop every entry in the map, leaving it empty.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Get an iterator over the entries in the map, in insertion order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+
+    /// Initialize an empty map on the stack
+    pub const fn new() -> Self {
+        StackMap {
+            entries: StackList::new()
+        }
+    }
+}
+
+impl<K: PartialEq, V, const S: usize> Default for StackMap<K, V, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: PartialEq + Debug, V: Debug, const S: usize> Debug for StackMap<K, V, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_insert_get() {
+        let mut map: StackMap<&str, i32, 4> = StackMap::new();
+
+        assert!(map.insert("a", 1).expect("Couldn't insert.").is_none());
+        assert!(map.insert("b", 2).expect("Couldn't insert.").is_none());
+        assert!(map.get(&"a") == Some(&1));
+        assert!(map.get(&"b") == Some(&2));
+        assert!(map.get(&"c").is_none());
+        assert!(map.contains_key(&"a"));
+        assert!(map.len() == 2);
+    }
+
+    #[test]
+    fn test_update() {
+        let mut map: StackMap<&str, i32, 2> = StackMap::new();
+
+        map.insert("a", 1).expect("Couldn't insert.");
+        assert!(map.insert("a", 10).expect("Couldn't insert.") == Some(1));
+        assert!(map.len() == 1);
+
+        *map.get_mut(&"a").expect("Key missing.") += 5;
+        assert!(map.get(&"a") == Some(&15));
+        assert!(map.get_mut(&"b").is_none());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut map: StackMap<u8, char, 4> = StackMap::new();
+
+        for (k, v) in [(1, 'a'), (2, 'b'), (3, 'c')] {
+            map.insert(k, v).expect("Couldn't insert.");
+        }
+
+        assert!(map.remove(&2) == Some('b'));
+        assert!(map.remove(&2).is_none());
+        assert!(map.len() == 2);
+        assert!(map.iter().eq([(&1, &'a'), (&3, &'c')]));
+    }
+
+    #[test]
+    fn test_full() {
+        let mut map: StackMap<u8, u8, 2> = StackMap::new();
+
+        map.insert(1, 1).expect("Couldn't insert.");
+        map.insert(2, 2).expect("Couldn't insert.");
+        assert!(map.is_full());
+        assert!(matches!(map.insert(3, 3), Err(ListError::ListFull((3, 3)))));
+
+        // Updating an existing key still works when full.
+        assert!(map.insert(2, 20).expect("Couldn't insert.") == Some(2));
+        assert!(map.get(&2) == Some(&20));
+    }
+}
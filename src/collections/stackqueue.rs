@@ -0,0 +1,149 @@
+//! This module describes a first-in first-out queue which is stored entirely
+//! on the stack. It is a thin adapter over `StackDeque`, so items are enqueued
+//! at the back and dequeued from the front in constant time. A user must
+//! specify maximum capacity and contained type at compile time. An error is
+//! returned if attempting to enqueue past the maximum capacity.
+
+use core::fmt::Debug;
+
+use super::stackdeque::{StackDeque, StackDequeIter};
+use super::stacklist::ListError;
+
+/// A FIFO queue stored on the stack of type T up to a maximum number of items S
+pub struct StackQueue<T: Sized, const S: usize> {
+    deque: StackDeque<T, S>
+}
+
+impl<T: Sized, const S: usize> StackQueue<T, S> {
+    /// Add an item to the back of the queue.
+    /// If the queue is full, the item is handed back inside the error.
+    pub fn enqueue(&mut self, item: T) -> Result<(), ListError<T>> {
+        self.deque.push_back(item)
+    }
+
+    /// Add an item to the back of the queue. Alias of `enqueue`.
+    pub fn push_back(&mut self, item: T) -> Result<(), ListError<T>> {
+        self.enqueue(item)
+    }
+
+    /// Remove and return the item at the front of the queue. None if empty.
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+
+    /// Remove and return the item at the front of the queue. Alias of `dequeue`.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.dequeue()
+    }
+
+    /// Return a reference to the item which would be dequeued next. None if empty.
+    pub fn front(&self) -> Option<&T> {
+        self.deque.front()
+    }
+
+    /// Returns the number of items currently in the queue.
+    pub fn len(&self) -> usize {
+        self.deque.len()
+    }
+
+    /// Returns the maximum number of items the queue can hold.
+    pub const fn capacity(&self) -> usize {
+        S
+    }
+
+    /// Returns true if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
+
+    /// Returns true if the queue is full.
+    pub fn is_full(&self) -> bool {
+        self.deque.is_full()
+    }
+
+    /// Drop every item in the queue, leaving it empty.
+    pub fn clear(&mut self) {
+        self.deque.clear();
+    }
+
+    /// Get an iterator over the items in the queue, in the order they would be dequeued.
+    pub fn iter(&self) -> StackDequeIter<'_, T, S> {
+        self.deque.iter()
+    }
+
+    /// Initialize an empty queue on the stack
+    pub const fn new() -> Self {
+        StackQueue {
+            deque: StackDeque::new()
+        }
+    }
+}
+
+impl<T: Sized, const S: usize> Default for StackQueue<T, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug, const S: usize> Debug for StackQueue<T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fifo_order() {
+        let mut queue: StackQueue<i32, 4> = StackQueue::new();
+
+        queue.enqueue(1).expect("Couldn't enqueue.");
+        queue.push_back(2).expect("Couldn't enqueue.");
+        queue.enqueue(3).expect("Couldn't enqueue.");
+        assert!(queue.front() == Some(&1));
+        assert!(queue.iter().copied().eq([1, 2, 3]));
+
+        assert!(queue.dequeue() == Some(1));
+        assert!(queue.pop_front() == Some(2));
+        assert!(queue.dequeue() == Some(3));
+        assert!(queue.dequeue().is_none());
+        assert!(queue.front().is_none());
+    }
+
+    #[test]
+    fn test_full() {
+        let mut queue: StackQueue<i32, 2> = StackQueue::new();
+
+        queue.enqueue(1).expect("Couldn't enqueue.");
+        queue.enqueue(2).expect("Couldn't enqueue.");
+        assert!(queue.is_full());
+        assert!(queue.len() == 2);
+        assert!(matches!(queue.enqueue(3), Err(ListError::ListFull(3))));
+
+        queue.dequeue();
+        assert!(!queue.is_full());
+        queue.enqueue(3).expect("Couldn't enqueue.");
+        assert!(queue.iter().copied().eq([2, 3]));
+    }
+
+    #[test]
+    fn test_wraparound() {
+        let mut queue: StackQueue<i32, 3> = StackQueue::new();
+
+        // Keep two items in flight so the head walks round the ring several times.
+        queue.enqueue(0).expect("Couldn't enqueue.");
+        for i in 1..20 {
+            queue.enqueue(i).expect("Couldn't enqueue.");
+            assert!(queue.dequeue() == Some(i - 1));
+        }
+
+        assert!(queue.len() == 1);
+        assert!(queue.front() == Some(&19));
+        queue.enqueue(20).expect("Couldn't enqueue.");
+        queue.enqueue(21).expect("Couldn't enqueue.");
+        assert!(queue.is_full());
+        assert!(queue.iter().copied().eq([19, 20, 21]));
+    }
+}
@@ -0,0 +1,145 @@
+//! This module describes a small set stored on the stack, backed by a `StackList` in the same way as `StackMap`.
+
+use core::fmt::Debug;
+
+use super::stacklist::{ListError, StackList, StackListIter};
+
+/// A set stored on the stack of type T up to a maximum number of items S
+pub struct StackSet<T: PartialEq, const S: usize> {
+    items: StackList<T, S>
+}
+
+impl<T: PartialEq, const S: usize> StackSet<T, S> {
+    /// Insert an item into the set.
+    /// Returns false without changing the set if an equal item is already present.
+    /// If the item is new and the set is full, it is handed back inside the error.
+    pub fn insert(&mut self, item: T) -> Result<bool, ListError<T>> {
+        if self.contains(&item) {
+            return Ok(false)
+        }
+
+        self.items.push(item)?;
+
+        Ok(true)
+    }
+
+    /// Returns true if the set contains an item equal to the one given.
+    pub fn contains(&self, item: &T) -> bool {
+        self.items.contains(item)
+    }
+
+    /// Remove the item equal to the one given. Returns false if it was not present.
+    /// The remaining items keep their insertion order.
+    pub fn remove(&mut self, item: &T) -> bool {
+        match self.items.iter().position(|i| i == item) {
+            Some(index) => {
+                self.items.remove(index);
+                true
+            }
+            None => false
+        }
+    }
+
+    /// Returns the number of items currently in the set.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns the maximum number of items the set can hold.
+    pub const fn capacity(&self) -> usize {
+        S
+    }
+
+    /// Returns true if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns true if the set is full.
+    pub fn is_full(&self) -> bool {
+        self.items.is_full()
+    }
+
+    /// Drop every item in the set, leaving it empty.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Get an iterator over the items in the set, in insertion order.
+    pub fn iter(&self) -> StackListIter<'_, T, S> {
+        self.items.iter()
+    }
+
+    /// Initialize an empty set on the stack
+    pub const fn new() -> Self {
+        StackSet {
+            items: StackList::new()
+        }
+    }
+}
+
+impl<T: PartialEq, const S: usize> Default for StackSet<T, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq + Debug, const S: usize> Debug for StackSet<T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_insert_contains() {
+        let mut set: StackSet<i32, 4> = StackSet::new();
+
+        assert!(set.insert(1).expect("Couldn't insert."));
+        assert!(set.insert(2).expect("Couldn't insert."));
+        assert!(set.contains(&1));
+        assert!(set.contains(&2));
+        assert!(!set.contains(&3));
+        assert!(set.iter().copied().eq([1, 2]));
+    }
+
+    #[test]
+    fn test_duplicate() {
+        let mut set: StackSet<&str, 4> = StackSet::new();
+
+        assert!(set.insert("a").expect("Couldn't insert."));
+        assert!(!set.insert("a").expect("Couldn't insert."));
+        assert!(!set.insert("a").expect("Couldn't insert."));
+        assert!(set.len() == 1);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set: StackSet<i32, 4> = StackSet::new();
+
+        for i in 1..=3 {
+            set.insert(i).expect("Couldn't insert.");
+        }
+
+        assert!(set.remove(&2));
+        assert!(!set.remove(&2));
+        assert!(!set.contains(&2));
+        assert!(set.iter().copied().eq([1, 3]));
+    }
+
+    #[test]
+    fn test_full() {
+        let mut set: StackSet<i32, 2> = StackSet::new();
+
+        set.insert(1).expect("Couldn't insert.");
+        set.insert(2).expect("Couldn't insert.");
+        assert!(set.is_full());
+        assert!(matches!(set.insert(3), Err(ListError::ListFull(3))));
+
+        // A duplicate is not an error even when full.
+        assert!(!set.insert(2).expect("Couldn't insert."));
+    }
+}
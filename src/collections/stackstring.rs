@@ -0,0 +1,146 @@
+//! This module describes a UTF-8 string stored entirely on the stack, up to a
+//! maximum number of bytes specified at compile time. An error is returned if
+//! attempting to append past the maximum capacity.
+
+use core::{fmt::{Debug, Display}, str};
+
+/// An error returned by fallible string operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StringError {
+    /// Raised when attempting to append to the string would overflow
+    StringFull,
+}
+
+impl Display for StringError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::StringFull => write!(f, "The string is full.")
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StringError {}
+
+/// A UTF-8 string stored on the stack of up to S bytes
+pub struct StackString<const S: usize> {
+    data: [u8; S],
+    len: usize
+}
+
+impl<const S: usize> StackString<S> {
+    /// Append a string slice to the end of the string.
+    /// If it won't fit, an error is returned and nothing is appended.
+    pub fn push_str(&mut self, string: &str) -> Result<(), StringError> {
+        if string.len() > S - self.len {
+            return Err(StringError::StringFull)
+        }
+
+        self.data[self.len..self.len + string.len()].copy_from_slice(string.as_bytes());
+        self.len += string.len();
+
+        Ok(())
+    }
+
+    /// Append a character to the end of the string.
+    /// If its encoding won't fit, an error is returned and nothing is appended.
+    pub fn push(&mut self, c: char) -> Result<(), StringError> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Get the contents as a string slice.
+    pub fn as_str(&self) -> &str {
+        // Only whole str slices are ever copied in, so the written bytes are always valid UTF-8.
+        unsafe { str::from_utf8_unchecked(&self.data[..self.len]) }
+    }
+
+    /// Returns the length of the string in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the maximum number of bytes the string can hold.
+    pub const fn capacity(&self) -> usize {
+        S
+    }
+
+    /// Returns true if the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Initialize an empty string on the stack
+    pub const fn new() -> Self {
+        StackString {
+            data: [0; S],
+            len: 0
+        }
+    }
+}
+
+impl<const S: usize> Default for StackString<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const S: usize> Debug for StackString<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const S: usize> Display for StackString<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_push_ascii() {
+        let mut string: StackString<8> = StackString::new();
+        assert!(string.is_empty());
+
+        string.push_str("e2e4").expect("Couldn't push to string.");
+        string.push(' ').expect("Couldn't push to string.");
+        assert!(string.as_str() == "e2e4 ");
+        assert!(string.len() == 5);
+        assert!(string.capacity() == 8);
+    }
+
+    #[test]
+    fn test_push_multibyte() {
+        let mut string: StackString<8> = StackString::new();
+
+        string.push('é').expect("Couldn't push to string.");
+        string.push_str("♞").expect("Couldn't push to string.");
+        assert!(string.as_str() == "é♞");
+        assert!(string.len() == 5); // Length counts bytes, not characters
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut string: StackString<4> = StackString::new();
+
+        string.push_str("abc").expect("Couldn't push to string.");
+        assert!(matches!(string.push('é'), Err(StringError::StringFull))); // Two bytes, only one left
+        assert!(matches!(string.push_str("de"), Err(StringError::StringFull)));
+        assert!(string.as_str() == "abc"); // Nothing partial was written
+
+        string.push('d').expect("Couldn't push to string.");
+        assert!(string.len() == string.capacity());
+    }
+
+    #[test]
+    fn test_display() {
+        let mut string: StackString<16> = StackString::new();
+        string.push_str("d7d5 ♛").expect("Couldn't push to string.");
+
+        assert!(string.to_string() == "d7d5 ♛");
+        assert!(format!("{:?}", string) == "\"d7d5 ♛\"");
+    }
+}
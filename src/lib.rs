@@ -1 +1,3 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
 pub mod collections;
\ No newline at end of file